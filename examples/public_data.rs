@@ -1,10 +1,11 @@
 use polymarket_rs::client::DataClient;
+use polymarket_rs::config::hosts;
 use polymarket_rs::Result;
 
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create a DataClient for accessing position and portfolio data
-    let client = DataClient::new("https://data-api.polymarket.com");
+    let client = DataClient::new(hosts::DATA_API);
 
     // Example wallet address
     let user_address = "0xe0368af7f5777989b927b7ad0d420562fee8616c";
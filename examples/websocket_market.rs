@@ -22,6 +22,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         max_delay: Duration::from_secs(30),
         multiplier: 2.0,
         max_attempts: None, // Infinite reconnection attempts
+        warm_standby: false,
     };
 
     // Create a reconnecting stream that will automatically reconnect on disconnection
@@ -69,7 +70,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 price_change.side,
                                 price_change.price,
                                 price_change.size,
-                                if price_change.size.is_zero() {
+                                if price_change.is_removal() {
                                     "removed"
                                 } else {
                                     "updated"
@@ -1,8 +1,15 @@
 use crate::error::{Error, Result};
 use crate::http::{create_l1_headers, create_l2_headers, HttpClient};
-use crate::signing::EthSigner;
-use crate::types::{ApiCreds, ApiKeysResponse, BalanceAllowanceParams};
+use crate::orders::{eth_call_request, nonces_call, EthCallResponse};
+use crate::signing::{EthSigner, SharedSigner};
+use crate::types::{
+    ApiCreds, ApiKeysResponse, BalanceAllowance, BalanceAllowanceParams, SignatureType,
+};
+use crate::{OrderBuilder, TradingClient};
 use alloy_primitives::{Address, U256};
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 /// Client for authenticated operations
 ///
@@ -51,6 +58,8 @@ impl AuthenticatedClient {
         }
     }
 
+    http_client_builders!();
+
     /// Get the API credentials if available
     ///
     /// Returns a reference to the API credentials if they were provided when creating
@@ -127,10 +136,69 @@ impl AuthenticatedClient {
         self.api_creds = api_creds;
     }
 
+    /// Turn this client into a [`TradingClient`] for the same account
+    ///
+    /// Reuses the signer, chain id, API credentials and HTTP configuration
+    /// (host, transport, default headers and path prefix), and builds the
+    /// [`OrderBuilder`] from the same signer.
+    ///
+    /// # Arguments
+    /// * `signature_type` - Signature type for orders (defaults to EOA if None)
+    /// * `funder` - Funder of the orders (defaults to this client's funder,
+    ///   then to the signer address)
+    ///
+    /// # Errors
+    /// Returns [`Error::AuthRequired`] if no API credentials are set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::AuthenticatedClient;
+    /// # use alloy_signer_local::PrivateKeySigner;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let signer = PrivateKeySigner::random();
+    /// let mut auth_client =
+    ///     AuthenticatedClient::new("https://clob.polymarket.com", signer, 137, None, None);
+    /// let creds = auth_client.create_or_derive_api_key().await?;
+    /// auth_client.set_api_creds(Some(creds));
+    ///
+    /// let trading_client = auth_client.into_trading_client(None, None)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_trading_client(
+        self,
+        signature_type: Option<SignatureType>,
+        funder: Option<Address>,
+    ) -> Result<TradingClient> {
+        let api_creds = self
+            .api_creds
+            .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
+        let signer = SharedSigner::new(self.signer);
+        let order_builder =
+            OrderBuilder::new(signer.clone(), signature_type, funder.or(self.funder));
+
+        Ok(TradingClient::from_http_client(
+            self.http_client,
+            signer,
+            self.chain_id,
+            api_creds,
+            order_builder,
+        ))
+    }
+
     /// Create a new API key (L1 authentication required)
     ///
     /// This creates a new API key for the signer's address.
     /// Requires wallet signature.
+    ///
+    /// # Nonce
+    /// The nonce is part of the signed L1 auth message and defaults to 0. Each
+    /// nonce can be used to create one key per address; creating again with a
+    /// used nonce is rejected by the server. Use a fresh nonce to create an
+    /// additional key, or [`create_api_key_with_retry`](Self::create_api_key_with_retry)
+    /// to find the next free one automatically.
     pub async fn create_api_key(&self, nonce: Option<U256>) -> Result<ApiCreds> {
         let headers = create_l1_headers(&self.signer, self.chain_id, nonce)?;
         self.http_client
@@ -138,6 +206,33 @@ impl AuthenticatedClient {
             .await
     }
 
+    /// Create a new API key, bumping the nonce when it has already been used
+    ///
+    /// Starts at `start_nonce` (0 if `None`) and tries up to `max_attempts`
+    /// consecutive nonces. Only nonce-related rejections are retried; any other
+    /// error is returned immediately. Returns the credentials along with the
+    /// nonce that produced them.
+    pub async fn create_api_key_with_retry(
+        &self,
+        start_nonce: Option<U256>,
+        max_attempts: u32,
+    ) -> Result<(ApiCreds, U256)> {
+        let mut nonce = start_nonce.unwrap_or(U256::ZERO);
+        let mut attempt = 1;
+        loop {
+            match self.create_api_key(Some(nonce)).await {
+                Ok(creds) => return Ok((creds, nonce)),
+                Err(e) if attempt < max_attempts && is_nonce_error(&e) => {
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record(|m| m.retry("create_api_key"));
+                    nonce += U256::from(1);
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     /// Derive API key from existing credentials (L1 authentication required)
     pub async fn derive_api_key(&self) -> Result<ApiCreds> {
         let headers = create_l1_headers(&self.signer, self.chain_id, None)?;
@@ -190,50 +285,74 @@ impl AuthenticatedClient {
         &self,
         params: BalanceAllowanceParams,
     ) -> Result<serde_json::Value> {
-        let api_creds = self
-            .api_creds
-            .as_ref()
-            .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
+        self.fetch_balance_allowance(&params).await
+    }
 
-        // IMPORTANT: Sign the base path WITHOUT query parameters
-        let base_path = "/balance-allowance";
-        let headers = create_l2_headers::<_, ()>(&self.signer, api_creds, "GET", base_path, None)?;
-
-        // Build the full request path WITH query parameters
-        let query_params = params.to_query_params();
-        let request_path = if query_params.is_empty() {
-            base_path.to_string()
-        } else {
-            format!(
-                "{}?{}",
-                base_path,
-                query_params
-                    .iter()
-                    .map(|(k, v)| format!("{}={}", k, v))
-                    .collect::<Vec<_>>()
-                    .join("&")
-            )
-        };
+    /// Get balances for several assets at once (L2 authentication required)
+    ///
+    /// Typically called with one collateral entry plus one conditional entry per
+    /// position token. Requests are sent concurrently, each with its own L2
+    /// signature. Results are returned in the same order as `assets`; the first
+    /// failing request fails the whole call.
+    ///
+    /// # Arguments
+    /// * `assets` - Query parameters for each asset
+    pub async fn get_balances(
+        &self,
+        assets: &[BalanceAllowanceParams],
+    ) -> Result<Vec<BalanceAllowance>> {
+        futures_util::future::try_join_all(
+            assets
+                .iter()
+                .map(|params| self.fetch_balance_allowance(params)),
+        )
+        .await
+    }
 
-        self.http_client.get(&request_path, Some(headers)).await
+    async fn fetch_balance_allowance<T: DeserializeOwned>(
+        &self,
+        params: &BalanceAllowanceParams,
+    ) -> Result<T> {
+        self.balance_allowance_request("/balance-allowance", params)
+            .await
     }
 
-    /// Update balance allowance (L2 authentication required)
-    pub async fn update_balance_allowance(&self) -> Result<serde_json::Value> {
+    /// L2-signed GET of a balance-allowance endpoint with `params` as query
+    async fn balance_allowance_request<T: DeserializeOwned>(
+        &self,
+        base_path: &str,
+        params: &BalanceAllowanceParams,
+    ) -> Result<T> {
         let api_creds = self
             .api_creds
             .as_ref()
             .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
-
-        let headers = create_l2_headers::<_, ()>(
+        balance_allowance_request(
+            &self.http_client,
             &self.signer,
             api_creds,
-            "GET",
-            "/balance-allowance/update",
-            None,
-        )?;
-        self.http_client
-            .get("/balance-allowance/update", Some(headers))
+            base_path,
+            params,
+        )
+        .await
+    }
+
+    /// Refresh the exchange's cached balance and allowance for one asset (L2
+    /// authentication required)
+    ///
+    /// The exchange tracks collateral and each conditional token separately,
+    /// so after approving or receiving an asset, update that asset: collateral
+    /// with `asset_type(AssetType::Collateral)`, a position token with
+    /// `asset_type(AssetType::Conditional)` and its `token_id`. Proxy and Safe
+    /// wallets also set their `signature_type`.
+    ///
+    /// # Arguments
+    /// * `params` - The asset to update
+    pub async fn update_balance_allowance(
+        &self,
+        params: BalanceAllowanceParams,
+    ) -> Result<serde_json::Value> {
+        self.balance_allowance_request("/balance-allowance/update", &params)
             .await
     }
 
@@ -269,9 +388,76 @@ impl AuthenticatedClient {
             .await
     }
 
-    /// Get the signer's address
+    /// Send an L2-authenticated request with any HTTP method
+    ///
+    /// Escape hatch for endpoints this client doesn't wrap yet (e.g. `PUT` or
+    /// `PATCH` endpoints). The method is case-insensitive. As with the built-in
+    /// endpoints, the signature covers the path without its query string.
+    ///
+    /// # Arguments
+    /// * `method` - HTTP method, e.g. `"PUT"`
+    /// * `path` - Request path, optionally with a query string
+    /// * `body` - Optional JSON body
+    pub async fn l2_request<T, B>(&self, method: &str, path: &str, body: Option<&B>) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        let api_creds = self
+            .api_creds
+            .as_ref()
+            .ok_or_else(|| Error::AuthRequired("API credentials required".to_string()))?;
+
+        let method = Method::from_bytes(method.to_ascii_uppercase().as_bytes())
+            .map_err(|e| Error::InvalidParameter(format!("invalid HTTP method: {}", e)))?;
+        let base_path = path.split('?').next().unwrap_or(path);
+        let headers = create_l2_headers(&self.signer, api_creds, method.as_str(), base_path, body)?;
+        self.http_client
+            .request(method, path, body, Some(headers))
+            .await
+    }
+
+    /// Get the order maker's current exchange nonce
+    ///
+    /// Reads `nonces(maker)` from the exchange contract through the JSON-RPC
+    /// endpoint `rpc_url` (a Polygon node, not the CLOB host). The maker is the
+    /// funder address if set, otherwise the signer. Orders are only valid while
+    /// their `nonce` equals this value; see
+    /// [`increment_nonce_call`](crate::orders::increment_nonce_call) to
+    /// invalidate all of them at once.
+    ///
+    /// # Arguments
+    /// * `rpc_url` - Polygon JSON-RPC endpoint
+    /// * `neg_risk` - Whether to read the neg risk exchange's nonce
+    pub async fn get_nonce(&self, rpc_url: &str, neg_risk: bool) -> Result<U256> {
+        let maker = self.funder.unwrap_or_else(|| self.signer.address());
+        let call = nonces_call(self.chain_id, neg_risk, maker)?;
+        let response: EthCallResponse = self
+            .http_client
+            .for_base_url(rpc_url)
+            .post("", &eth_call_request(&call), None)
+            .await?;
+        response.into_u256()
+    }
+
+    /// Get the signer's address as an EIP-55 checksummed string
+    ///
+    /// Use [`signer_address`](Self::signer_address) for the [`Address`] itself.
     pub fn get_address(&self) -> String {
-        format!("{:?}", self.signer.address())
+        self.signer.address().to_checksum(None)
+    }
+
+    /// Get the signer's address as an EIP-55 checksummed string
+    ///
+    /// Same as [`get_address`](Self::get_address), for callers that want the
+    /// format spelled out at the call site.
+    pub fn get_address_checksummed(&self) -> String {
+        self.get_address()
+    }
+
+    /// Get the signer's address
+    pub fn signer_address(&self) -> Address {
+        self.signer.address()
     }
 
     /// Get the funder address (for PolyProxy wallets)
@@ -282,3 +468,323 @@ impl AuthenticatedClient {
         self.funder
     }
 }
+
+/// Whether an API error indicates the L1 auth nonce was already used or invalid
+fn is_nonce_error(error: &Error) -> bool {
+    match error {
+        Error::Api { status, message } => {
+            (400..500).contains(status) && message.to_ascii_lowercase().contains("nonce")
+        }
+        _ => false,
+    }
+}
+
+/// L2-signed GET of a balance-allowance endpoint with `params` as query
+///
+/// Shared with [`TradingClient`]'s balance check.
+pub(super) async fn balance_allowance_request<S: EthSigner, T: DeserializeOwned>(
+    http_client: &HttpClient,
+    signer: &S,
+    api_creds: &ApiCreds,
+    base_path: &str,
+    params: &BalanceAllowanceParams,
+) -> Result<T> {
+    // IMPORTANT: Sign the base path WITHOUT query parameters
+    let headers = create_l2_headers::<_, ()>(signer, api_creds, "GET", base_path, None)?;
+
+    // Build the full request path WITH query parameters
+    let query_params = params.to_query_params();
+    let request_path = if query_params.is_empty() {
+        base_path.to_string()
+    } else {
+        format!(
+            "{}?{}",
+            base_path,
+            query_params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<_>>()
+                .join("&")
+        )
+    };
+
+    http_client.get(&request_path, Some(headers)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn client(mock: &MockTransport) -> AuthenticatedClient {
+        AuthenticatedClient::new(
+            "https://clob.example",
+            PrivateKeySigner::random(),
+            137,
+            None,
+            None,
+        )
+        .with_transport(mock.clone())
+    }
+
+    fn nonces(mock: &MockTransport) -> Vec<String> {
+        mock.requests()
+            .iter()
+            .map(|request| {
+                request
+                    .headers
+                    .iter()
+                    .find(|(name, _)| name == "POLY_NONCE")
+                    .map(|(_, value)| value.clone())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_with_retry_bumps_nonce() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/auth/api-key",
+            400,
+            r#"{"error": "nonce already used"}"#,
+        );
+
+        let result = client(&mock)
+            .create_api_key_with_retry(Some(U256::from(5)), 3)
+            .await;
+        assert!(matches!(result, Err(Error::Api { status: 400, .. })));
+        assert_eq!(nonces(&mock), ["5", "6", "7"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_with_retry_stops_on_other_errors() {
+        let mock = MockTransport::new().on("POST", "/auth/api-key", 401, "unauthorized");
+
+        let result = client(&mock).create_api_key_with_retry(None, 3).await;
+        assert!(matches!(result, Err(Error::Api { status: 401, .. })));
+        assert_eq!(nonces(&mock), ["0"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_with_retry_success() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/auth/api-key",
+            200,
+            r#"{"apiKey": "k", "secret": "s", "passphrase": "p"}"#,
+        );
+
+        let (creds, nonce) = client(&mock)
+            .create_api_key_with_retry(None, 3)
+            .await
+            .unwrap();
+        assert_eq!(creds.api_key, "k");
+        assert_eq!(nonce, U256::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_get_balances_signs_each_request() {
+        let mock = MockTransport::new().on(
+            "GET",
+            "/balance-allowance",
+            200,
+            r#"{"balance": "2500000", "allowances": {"0xexchange": "115792089237316195423570985008687907853269984665640564039457584007913129639935"}}"#,
+        );
+        let creds = ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = AuthenticatedClient::new(
+            "https://clob.example",
+            PrivateKeySigner::random(),
+            137,
+            Some(creds),
+            None,
+        )
+        .with_transport(mock.clone());
+
+        let assets = [
+            BalanceAllowanceParams::new().asset_type(crate::types::AssetType::Collateral),
+            BalanceAllowanceParams::new()
+                .asset_type(crate::types::AssetType::Conditional)
+                .token_id("123"),
+        ];
+        let balances = client.get_balances(&assets).await.unwrap();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].balance, rust_decimal_macros::dec!(2.5));
+        assert_eq!(balances[0].allowances.len(), 1);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].url.contains("token_id=123"));
+        for request in &requests {
+            assert!(request
+                .headers
+                .iter()
+                .any(|(name, _)| name == "POLY_SIGNATURE"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_balance_allowance_per_asset() {
+        let mock = MockTransport::new().on("GET", "/balance-allowance/update", 200, "{}");
+        let creds = ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = AuthenticatedClient::new(
+            "https://clob.example",
+            PrivateKeySigner::random(),
+            137,
+            Some(creds),
+            None,
+        )
+        .with_transport(mock.clone());
+
+        client
+            .update_balance_allowance(
+                BalanceAllowanceParams::new().asset_type(crate::types::AssetType::Collateral),
+            )
+            .await
+            .unwrap();
+        client
+            .update_balance_allowance(
+                BalanceAllowanceParams::new()
+                    .asset_type(crate::types::AssetType::Conditional)
+                    .token_id("123")
+                    .signature_type(1),
+            )
+            .await
+            .unwrap();
+
+        let urls: Vec<_> = mock.requests().into_iter().map(|r| r.url).collect();
+        assert_eq!(
+            urls,
+            [
+                "https://clob.example/balance-allowance/update?asset_type=COLLATERAL",
+                "https://clob.example/balance-allowance/update?asset_type=CONDITIONAL&token_id=123&signature_type=1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_l2_request_arbitrary_method() {
+        let mock = MockTransport::new().on("PUT", "/some/endpoint", 200, "{}");
+        let creds = ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let client = AuthenticatedClient::new(
+            "https://clob.example",
+            PrivateKeySigner::random(),
+            137,
+            Some(creds),
+            None,
+        )
+        .with_transport(mock.clone());
+
+        let _: serde_json::Value = client
+            .l2_request(
+                "put",
+                "/some/endpoint?x=1",
+                Some(&serde_json::json!({"a": 1})),
+            )
+            .await
+            .unwrap();
+
+        let request = &mock.requests()[0];
+        assert_eq!(request.method, Method::PUT);
+        assert!(request
+            .headers
+            .iter()
+            .any(|(name, _)| name == "POLY_SIGNATURE"));
+    }
+
+    #[test]
+    fn test_address_accessors() {
+        let signer = PrivateKeySigner::random();
+        let address = signer.address();
+        let client = AuthenticatedClient::new("https://clob.example", signer, 137, None, None);
+
+        assert_eq!(client.signer_address(), address);
+        assert_eq!(client.get_address(), address.to_checksum(None));
+        assert_eq!(
+            Address::parse_checksummed(client.get_address(), None).unwrap(),
+            address
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_nonce_reads_exchange() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/",
+            200,
+            r#"{"jsonrpc": "2.0", "id": 1, "result": "0x0000000000000000000000000000000000000000000000000000000000000002"}"#,
+        );
+        let client = client(&mock);
+
+        let nonce = client
+            .get_nonce("https://rpc.example/", false)
+            .await
+            .unwrap();
+        assert_eq!(nonce, U256::from(2));
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].url, "https://rpc.example/");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["method"], "eth_call");
+        let data = body["params"][0]["data"].as_str().unwrap();
+        assert!(data.starts_with("0x7ecebe00"));
+        let maker = client.signer_address().to_string().to_lowercase();
+        assert!(data.ends_with(&maker[2..]));
+    }
+
+    #[tokio::test]
+    async fn test_into_trading_client() {
+        let signer = PrivateKeySigner::random();
+        let signer_addr = signer.address();
+        let funder = Address::repeat_byte(0x11);
+        let mock = MockTransport::new().on(
+            "GET",
+            "/data/orders",
+            200,
+            r#"{"limit": 100, "count": 0, "next_cursor": "LTE=", "data": []}"#,
+        );
+        let mut auth_client =
+            AuthenticatedClient::new("https://clob.example", signer, 137, None, Some(funder))
+                .with_transport(mock.clone());
+        assert!(matches!(
+            client(&mock).into_trading_client(None, None),
+            Err(Error::AuthRequired(_))
+        ));
+
+        auth_client.set_api_creds(Some(ApiCreds::new(
+            "key".into(),
+            "c2VjcmV0".into(),
+            "pass".into(),
+        )));
+        let trading = auth_client
+            .into_trading_client(Some(SignatureType::PolyProxy), None)
+            .unwrap();
+
+        let order_args = crate::types::OrderArgs::new(
+            "1234",
+            rust_decimal::Decimal::new(5, 1),
+            rust_decimal::Decimal::from(10),
+            crate::types::Side::Buy,
+        );
+        let options = crate::types::CreateOrderOptions::new()
+            .tick_size(rust_decimal::Decimal::new(1, 2))
+            .neg_risk(false);
+        let order = trading
+            .create_order(&order_args, None, None, options)
+            .unwrap();
+        assert_eq!(order.maker, funder.to_checksum(None));
+        assert_eq!(order.signer, signer_addr.to_checksum(None));
+        assert_eq!(order.signature_type, SignatureType::PolyProxy.to_u8());
+
+        // Requests go through the original transport with the same credentials
+        trading.get_orders(Default::default()).await.unwrap();
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .headers
+            .iter()
+            .any(|(name, value)| name == "POLY_API_KEY" && value == "key"));
+    }
+}
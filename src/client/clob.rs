@@ -2,11 +2,17 @@ use crate::error::Result;
 use crate::http::HttpClient;
 use crate::request::PaginationParams;
 use crate::types::{
-    BookParams, ConditionId, Market, MarketsResponse, MidpointResponse, NegRiskResponse,
-    OrderBookSummary, PriceHistoryResponse, PriceResponse, SimplifiedMarketsResponse,
-    SpreadResponse, TickSizeResponse, TokenId,
+    ActivityEvent, BookParams, BookStats, ConditionId, HealthStatus, Market, MarketIdsResponse,
+    MarketsResponse, MidpointResponse, NegRiskResponse, OrderBookSummary, PriceHistoryResponse,
+    PriceResponse, SimplifiedMarketsResponse, SpreadResponse, TickSizeResponse, TokenId,
 };
+use crate::websocket::OrderBook;
 use crate::Side;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
 /// Client for CLOB (Central Limit Order Book) market data APIs
 ///
@@ -14,6 +20,37 @@ use crate::Side;
 /// without requiring authentication.
 pub struct ClobClient {
     http_client: HttpClient,
+    cache: Option<ResponseCache>,
+}
+
+/// In-memory cache of raw JSON responses keyed by request path
+struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, serde_json::Value)>>,
+}
+
+impl ResponseCache {
+    fn entries(&self) -> MutexGuard<'_, HashMap<String, (Instant, serde_json::Value)>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn get(&self, path: &str) -> Option<serde_json::Value> {
+        let mut entries = self.entries();
+        match entries.get(path) {
+            Some((stored_at, value)) if stored_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(path);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, path: String, value: serde_json::Value) {
+        self.entries().insert(path, (Instant::now(), value));
+    }
 }
 
 impl ClobClient {
@@ -24,9 +61,56 @@ impl ClobClient {
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
+            cache: None,
         }
     }
 
+    http_client_builders!();
+
+    /// Cache market metadata lookups for `ttl`
+    ///
+    /// Responses of [`get_market`](Self::get_market), [`get_tick_size`](Self::get_tick_size)
+    /// and [`get_neg_risk`](Self::get_neg_risk) are kept in memory and reused
+    /// for repeated lookups until they are `ttl` old. Other endpoints are never
+    /// cached. Errors are not cached.
+    ///
+    /// Tick sizes can change while a market is live (see
+    /// [`WsEvent::TickSizeChange`](crate::types::WsEvent::TickSizeChange)); call
+    /// [`clear_cache`](Self::clear_cache) when one arrives or keep `ttl` short.
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.cache = Some(ResponseCache {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        });
+        self
+    }
+
+    /// Drop all cached responses
+    ///
+    /// Does nothing if caching is not enabled.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.entries().clear();
+        }
+    }
+
+    /// GET `path`, going through the response cache if it is enabled
+    async fn get_cached<T: DeserializeOwned>(&self, path: String) -> Result<T> {
+        let Some(cache) = &self.cache else {
+            return self.http_client.get(&path, None).await;
+        };
+
+        let value = match cache.get(&path) {
+            Some(value) => value,
+            None => {
+                let value: serde_json::Value = self.http_client.get(&path, None).await?;
+                cache.insert(path, value.clone());
+                value
+            }
+        };
+        Ok(serde_json::from_value(value)?)
+    }
+
     /// Check if the server is responsive
     pub async fn get_ok(&self) -> Result<serde_json::Value> {
         self.http_client.get("/", None).await
@@ -37,6 +121,48 @@ impl ClobClient {
         self.http_client.get("/time", None).await
     }
 
+    /// Check that the server is up, for readiness probes
+    ///
+    /// Calls `/` and, if it succeeds, `/time`. [`HealthStatus::latency`] is
+    /// the round-trip time of the `/` request. Every failure, an unreachable
+    /// server, an error status or a misconfigured client alike, is reported
+    /// as `reachable: false` with the error message, so callers always get a
+    /// status to report; the server time is left out if `/time` fails or
+    /// returns something other than a Unix timestamp.
+    ///
+    /// Use [`get_ok`](Self::get_ok) for the raw response.
+    pub async fn health_check(&self) -> HealthStatus {
+        let started = Instant::now();
+        let ok = self.get_ok().await;
+        let latency = started.elapsed();
+
+        if let Err(e) = ok {
+            return HealthStatus {
+                reachable: false,
+                latency,
+                server_time: None,
+                error: Some(e.to_string()),
+            };
+        }
+
+        let server_time = match self.get_server_time().await {
+            Ok(value) => value
+                .as_u64()
+                .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok())),
+            Err(e) => {
+                log::warn!("Health check could not fetch server time: {}", e);
+                None
+            }
+        };
+
+        HealthStatus {
+            reachable: true,
+            latency,
+            server_time,
+            error: None,
+        }
+    }
+
     /// Get the midpoint price for a token
     ///
     /// # Arguments
@@ -46,15 +172,18 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get midpoint prices for multiple tokens
+    /// Get midpoint prices for multiple tokens, keyed by token ID
+    ///
+    /// Tokens the server has no midpoint for are absent from the map.
     ///
     /// # Arguments
     /// * `token_ids` - List of token IDs to query
-    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<Vec<MidpointResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/midpoints", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    pub async fn get_midpoints(&self, token_ids: &[TokenId]) -> Result<HashMap<TokenId, Decimal>> {
+        let params: Vec<_> = token_ids
+            .iter()
+            .map(|id| serde_json::json!({ "token_id": id.as_str() }))
+            .collect();
+        self.http_client.post("/midpoints", &params, None).await
     }
 
     /// Get the current price for a token
@@ -68,6 +197,24 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get the best bid and best ask for a token in one call
+    ///
+    /// Both sides are read from a single order book fetch. A side with no
+    /// liquidity is returned as `None`.
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    ///
+    /// # Returns
+    /// A `(best_bid, best_ask)` tuple
+    pub async fn get_bid_ask(
+        &self,
+        token_id: &TokenId,
+    ) -> Result<(Option<Decimal>, Option<Decimal>)> {
+        let book = self.get_order_book(token_id).await?;
+        Ok((book.best_bid(), book.best_ask()))
+    }
+
     /// Get prices for multiple tokens
     pub async fn get_prices(&self, token_ids: &[TokenId]) -> Result<Vec<PriceResponse>> {
         let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
@@ -110,24 +257,71 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
-    /// Get spreads for multiple tokens
-    pub async fn get_spreads(&self, token_ids: &[TokenId]) -> Result<Vec<SpreadResponse>> {
-        let ids: Vec<&str> = token_ids.iter().map(|id| id.as_str()).collect();
-        self.http_client
-            .post("/spreads", &serde_json::json!({ "token_ids": ids }), None)
-            .await
+    /// Get spreads for multiple tokens, keyed by token ID
+    ///
+    /// Tokens the server has no spread for are absent from the map.
+    pub async fn get_spreads(&self, token_ids: &[TokenId]) -> Result<HashMap<TokenId, Decimal>> {
+        let params: Vec<_> = token_ids
+            .iter()
+            .map(|id| serde_json::json!({ "token_id": id.as_str() }))
+            .collect();
+        self.http_client.post("/spreads", &params, None).await
+    }
+
+    /// Get midpoint, spread and best bid/ask for multiple tokens, keyed by token ID
+    ///
+    /// Issues the batch `/midpoints`, `/spreads` and `/books` requests
+    /// concurrently. Tokens the server returns nothing for are absent from
+    /// the map; individual values are `None` when only that piece is missing
+    /// (e.g. no asks on the book).
+    ///
+    /// # Arguments
+    /// * `token_ids` - The token IDs to query
+    pub async fn get_book_stats(
+        &self,
+        token_ids: &[TokenId],
+    ) -> Result<HashMap<TokenId, BookStats>> {
+        let book_params: Vec<BookParams> = token_ids
+            .iter()
+            .map(|id| BookParams::new(id.as_str(), Side::Buy))
+            .collect();
+        let (midpoints, spreads, books) = futures_util::try_join!(
+            self.get_midpoints(token_ids),
+            self.get_spreads(token_ids),
+            self.get_order_books(&book_params),
+        )?;
+
+        let books: HashMap<TokenId, OrderBookSummary> = books
+            .into_iter()
+            .map(|book| (TokenId::new(book.asset_id.clone()), book))
+            .collect();
+
+        Ok(token_ids
+            .iter()
+            .filter_map(|id| {
+                let book = books.get(id);
+                let stats = BookStats {
+                    midpoint: midpoints.get(id).copied(),
+                    spread: spreads.get(id).copied(),
+                    best_bid: book.and_then(|b| b.best_bid()),
+                    best_ask: book.and_then(|b| b.best_ask()),
+                };
+                (midpoints.contains_key(id) || spreads.contains_key(id) || book.is_some())
+                    .then(|| (id.clone(), stats))
+            })
+            .collect())
     }
 
     /// Get the minimum tick size for a token
     pub async fn get_tick_size(&self, token_id: &TokenId) -> Result<TickSizeResponse> {
         let path = format!("/tick-size?token_id={}", token_id.as_str());
-        self.http_client.get(&path, None).await
+        self.get_cached(path).await
     }
 
     /// Get whether a market uses negative risk
     pub async fn get_neg_risk(&self, condition_id: &ConditionId) -> Result<NegRiskResponse> {
         let path = format!("/neg-risk?condition_id={}", condition_id.as_str());
-        self.http_client.get(&path, None).await
+        self.get_cached(path).await
     }
 
     /// Get the order book for a token
@@ -139,11 +333,53 @@ impl ClobClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get the top of the order book for a token
+    ///
+    /// The API always returns the full book, so it is truncated client-side to
+    /// the best `levels` price levels per side (bids highest first, asks lowest first).
+    ///
+    /// # Arguments
+    /// * `token_id` - The token ID to query
+    /// * `levels` - Number of price levels to keep on each side
+    pub async fn get_order_book_depth(
+        &self,
+        token_id: &TokenId,
+        levels: usize,
+    ) -> Result<OrderBookSummary> {
+        let mut book = self.get_order_book(token_id).await?;
+        book.truncate(levels);
+        Ok(book)
+    }
+
     /// Get order books for multiple tokens
     pub async fn get_order_books(&self, params: &[BookParams]) -> Result<Vec<OrderBookSummary>> {
         self.http_client.post("/books", &params, None).await
     }
 
+    /// Get order books for multiple tokens as local books, keyed by token ID
+    ///
+    /// Fetches all books in one batch `/books` request. The returned
+    /// [`OrderBook`]s are synced and can be kept up to date by applying
+    /// market WebSocket events. Tokens the server returns no book for are
+    /// absent from the map.
+    ///
+    /// # Arguments
+    /// * `token_ids` - The token IDs to query
+    pub async fn get_order_books_map(
+        &self,
+        token_ids: &[TokenId],
+    ) -> Result<HashMap<TokenId, OrderBook>> {
+        let params: Vec<BookParams> = token_ids
+            .iter()
+            .map(|id| BookParams::new(id.as_str(), Side::Buy))
+            .collect();
+        let books = self.get_order_books(&params).await?;
+        Ok(books
+            .into_iter()
+            .map(|book| (TokenId::new(book.asset_id.clone()), OrderBook::from(book)))
+            .collect())
+    }
+
     /// Get the last trade price for a token
     pub async fn get_last_trade_price(&self, token_id: &TokenId) -> Result<PriceResponse> {
         let path = format!("/last-trade-price?token_id={}", token_id.as_str());
@@ -170,14 +406,7 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/sampling-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = cursor_path("/sampling-markets", pagination);
         self.http_client.get(&path, None).await
     }
 
@@ -189,14 +418,7 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/sampling-simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = cursor_path("/sampling-simplified-markets", pagination);
         self.http_client.get(&path, None).await
     }
 
@@ -208,14 +430,7 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<MarketsResponse> {
-        let mut path = "/markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = cursor_path("/markets", pagination);
         self.http_client.get(&path, None).await
     }
 
@@ -224,21 +439,31 @@ impl ClobClient {
         &self,
         pagination: Option<PaginationParams>,
     ) -> Result<SimplifiedMarketsResponse> {
-        let mut path = "/simplified-markets".to_string();
-        if let Some(p) = pagination {
-            let params = p.to_query_params();
-            if !params.is_empty() {
-                path.push_str("?next_cursor=");
-                path.push_str(&params[0].1);
-            }
-        }
+        let path = cursor_path("/simplified-markets", pagination);
+        self.http_client.get(&path, None).await
+    }
+
+    /// Get the condition and token IDs of markets, with pagination
+    ///
+    /// A slimmer alternative to
+    /// [`get_simplified_markets`](Self::get_simplified_markets) for building
+    /// an index: the same endpoint and cursor, but only the IDs are kept, so
+    /// metadata can be fetched lazily with [`get_market`](Self::get_market).
+    ///
+    /// # Arguments
+    /// * `pagination` - Pagination parameters
+    pub async fn get_market_ids(
+        &self,
+        pagination: Option<PaginationParams>,
+    ) -> Result<MarketIdsResponse> {
+        let path = cursor_path("/simplified-markets", pagination);
         self.http_client.get(&path, None).await
     }
 
     /// Get a specific market by condition ID
     pub async fn get_market(&self, condition_id: &ConditionId) -> Result<Market> {
         let path = format!("/markets/{}", condition_id.as_str());
-        self.http_client.get(&path, None).await
+        self.get_cached(path).await
     }
 
     /// Get a specific market by slug
@@ -249,13 +474,250 @@ impl ClobClient {
 
     /// Get live activity events for a market (trades and events)
     ///
+    /// Unknown activity kinds are returned as [`ActivityEvent::Other`]. For
+    /// the market's trade history as typed fills, see
+    /// [`DataClient::get_market_trades`](crate::DataClient::get_market_trades).
+    ///
     /// # Arguments
     /// * `condition_id` - The condition ID of the market
     pub async fn get_market_trades_events(
         &self,
         condition_id: &ConditionId,
-    ) -> Result<serde_json::Value> {
+    ) -> Result<Vec<ActivityEvent>> {
         let path = format!("/live-activity/events/{}", condition_id.as_str());
         self.http_client.get(&path, None).await
     }
 }
+
+/// Path with the pagination cursor, if any, as the `next_cursor` query parameter
+fn cursor_path(path: &str, pagination: Option<PaginationParams>) -> String {
+    match pagination.and_then(|p| p.next_cursor) {
+        Some(cursor) => format!("{}?next_cursor={}", path, cursor),
+        None => path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let mock = MockTransport::new().on("GET", "/", 200, r#""OK""#).on(
+            "GET",
+            "/time",
+            200,
+            "1700000000",
+        );
+        let client = ClobClient::new("https://clob.example").with_transport(mock);
+        let status = client.health_check().await;
+        assert!(status.reachable);
+        assert_eq!(status.server_time, Some(1_700_000_000));
+        assert_eq!(status.clock_offset(1_700_000_010), Some(-10));
+        assert!(status.error.is_none());
+
+        let mock = MockTransport::new().on("GET", "/", 503, r#"{"error": "unavailable"}"#);
+        let client = ClobClient::new("https://clob.example").with_transport(mock);
+        let status = client.health_check().await;
+        assert!(!status.reachable);
+        assert_eq!(status.server_time, None);
+        assert!(status.error.unwrap().contains("unavailable"));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_ids() {
+        let mock = MockTransport::new().on(
+            "GET",
+            "/simplified-markets?next_cursor=MTAw",
+            200,
+            r#"{"limit": 100, "count": 1, "next_cursor": "LTE=", "data": [{
+                "condition_id": "0xc",
+                "rewards": {"rates": null, "min_size": 0, "max_spread": 0},
+                "tokens": [{"token_id": "1", "outcome": "Yes", "price": 0.5, "winner": false},
+                           {"token_id": "2", "outcome": "No", "price": 0.5, "winner": false}],
+                "active": true, "closed": false, "archived": false, "accepting_orders": true
+            }]}"#,
+        );
+        let client = ClobClient::new("https://clob.example").with_transport(mock);
+
+        let page = client
+            .get_market_ids(Some(PaginationParams::with_cursor("MTAw")))
+            .await
+            .unwrap();
+        assert_eq!(page.next_cursor.as_deref(), Some("LTE="));
+        assert_eq!(page.next_cursor(), None);
+        assert_eq!(
+            page.data,
+            [(
+                ConditionId::new("0xc"),
+                vec![TokenId::new("1"), TokenId::new("2")]
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_bid_ask_with_mock_transport() {
+        let book = r#"{
+            "market": "0xabc",
+            "asset_id": "123",
+            "hash": "0x0",
+            "timestamp": "1700000000",
+            "bids": [{"price": "0.45", "size": "10"}, {"price": "0.48", "size": "5"}],
+            "asks": [{"price": "0.52", "size": "7"}]
+        }"#;
+        let mock = MockTransport::new().on("GET", "/book", 200, book);
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        let (bid, ask) = client.get_bid_ask(&TokenId::new("123")).await.unwrap();
+        assert_eq!(bid, Some(dec!(0.48)));
+        assert_eq!(ask, Some(dec!(0.52)));
+        assert_eq!(
+            mock.requests()[0].url,
+            "https://clob.example/book?token_id=123"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_midpoints_keyed_by_token() {
+        let mock =
+            MockTransport::new().on("POST", "/midpoints", 200, r#"{"1": "0.5", "2": "0.25"}"#);
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        let ids = [TokenId::new("1"), TokenId::new("2"), TokenId::new("3")];
+        let midpoints = client.get_midpoints(&ids).await.unwrap();
+        assert_eq!(midpoints.len(), 2);
+        assert_eq!(midpoints[&TokenId::new("1")], dec!(0.5));
+        assert_eq!(midpoints[&TokenId::new("2")], dec!(0.25));
+        assert!(!midpoints.contains_key(&TokenId::new("3")));
+
+        assert_eq!(
+            mock.requests()[0].body.as_deref(),
+            Some(r#"[{"token_id":"1"},{"token_id":"2"},{"token_id":"3"}]"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_spreads_keyed_by_token() {
+        let mock = MockTransport::new().on("POST", "/spreads", 200, r#"{"1": "0.02"}"#);
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        let spreads = client
+            .get_spreads(&[TokenId::new("1"), TokenId::new("2")])
+            .await
+            .unwrap();
+        assert_eq!(spreads.len(), 1);
+        assert_eq!(spreads[&TokenId::new("1")], dec!(0.02));
+    }
+
+    #[tokio::test]
+    async fn test_get_book_stats() {
+        let mock = MockTransport::new()
+            .on("POST", "/midpoints", 200, r#"{"1": "0.5", "2": "0.3"}"#)
+            .on("POST", "/spreads", 200, r#"{"1": "0.02", "2": "0.1"}"#)
+            .on(
+                "POST",
+                "/books",
+                200,
+                r#"[{"market": "0x1", "asset_id": "1", "hash": "h", "timestamp": "1",
+                     "bids": [{"price": "0.49", "size": "10"}],
+                     "asks": [{"price": "0.51", "size": "10"}]},
+                    {"market": "0x2", "asset_id": "2", "hash": "h", "timestamp": "1",
+                     "bids": [{"price": "0.25", "size": "10"}], "asks": []}]"#,
+            );
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        let ids = [TokenId::new("1"), TokenId::new("2"), TokenId::new("3")];
+        let stats = client.get_book_stats(&ids).await.unwrap();
+
+        assert_eq!(stats.len(), 2);
+        let first = &stats[&TokenId::new("1")];
+        assert_eq!(first.midpoint, Some(dec!(0.5)));
+        assert_eq!(first.spread, Some(dec!(0.02)));
+        assert_eq!(first.best_bid, Some(dec!(0.49)));
+        assert_eq!(first.best_ask, Some(dec!(0.51)));
+        assert_eq!(stats[&TokenId::new("2")].best_ask, None);
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_books_map() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/books",
+            200,
+            r#"[{"market": "0x1", "asset_id": "1", "hash": "h1", "timestamp": "1757908892351",
+                 "bids": [{"price": "0.48", "size": "5"}, {"price": "0.49", "size": "10"}],
+                 "asks": [{"price": "0.51", "size": "10"}]}]"#,
+        );
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        let ids = [TokenId::new("1"), TokenId::new("2")];
+        let books = client.get_order_books_map(&ids).await.unwrap();
+
+        assert_eq!(books.len(), 1);
+        let book = &books[&TokenId::new("1")];
+        assert!(book.is_synced());
+        assert_eq!(book.best_bid(), Some(dec!(0.49)));
+        assert_eq!(book.best_ask(), Some(dec!(0.51)));
+        assert_eq!(book.hash(), Some("h1"));
+        assert_eq!(book.timestamp(), Some(1757908892351));
+
+        let body: serde_json::Value =
+            serde_json::from_str(mock.requests()[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body[1]["token_id"], "2");
+    }
+
+    #[tokio::test]
+    async fn test_cache_reuses_metadata_responses() {
+        let mock = MockTransport::new()
+            .on("GET", "/tick-size", 200, r#"{"minimum_tick_size": 0.01}"#)
+            .on("GET", "/neg-risk", 200, r#"{"neg_risk": true}"#);
+        let client = ClobClient::new("https://clob.example")
+            .with_transport(mock.clone())
+            .with_cache(Duration::from_secs(60));
+        let token_id = TokenId::new("1");
+
+        for _ in 0..3 {
+            let tick = client.get_tick_size(&token_id).await.unwrap();
+            assert_eq!(tick.minimum_tick_size, dec!(0.01));
+        }
+        assert!(
+            client
+                .get_neg_risk(&ConditionId::new("0xc"))
+                .await
+                .unwrap()
+                .neg_risk
+        );
+        assert_eq!(mock.requests().len(), 2);
+
+        client.clear_cache();
+        client.get_tick_size(&token_id).await.unwrap();
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_cache_expires_after_ttl() {
+        let mock =
+            MockTransport::new().on("GET", "/tick-size", 200, r#"{"minimum_tick_size": 0.01}"#);
+        let client = ClobClient::new("https://clob.example")
+            .with_transport(mock.clone())
+            .with_cache(Duration::ZERO);
+
+        client.get_tick_size(&TokenId::new("1")).await.unwrap();
+        client.get_tick_size(&TokenId::new("1")).await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_cache_by_default() {
+        let mock =
+            MockTransport::new().on("GET", "/tick-size", 200, r#"{"minimum_tick_size": 0.01}"#);
+        let client = ClobClient::new("https://clob.example").with_transport(mock.clone());
+
+        client.get_tick_size(&TokenId::new("1")).await.unwrap();
+        client.get_tick_size(&TokenId::new("1")).await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+}
@@ -1,12 +1,29 @@
+use crate::client::ClobClient;
 use crate::error::Result;
 use crate::http::HttpClient;
 use crate::request::{ActivityQueryParams, TradeQueryParams};
-use crate::types::{Activity, ClosedPosition, Position, PositionValue, Trade};
+use crate::types::{
+    Activity, ClosedPosition, ConditionId, PortfolioPnl, Position, PositionValue, TokenId, Trade,
+};
+use alloy_primitives::Address;
+use std::collections::HashMap;
 
 /// Client for accessing position and portfolio data
 ///
 /// This client provides access to user positions and portfolio values.
 /// It does not require authentication.
+///
+/// It talks to the data API ([`hosts::DATA_API`](crate::config::hosts::DATA_API)),
+/// a different host than the CLOB API used by [`ClobClient`]; none of these
+/// endpoints exist on the CLOB host, so pointing this client at it fails
+/// with 404s.
+///
+/// ```
+/// use polymarket_rs::config::hosts;
+/// use polymarket_rs::DataClient;
+///
+/// let client = DataClient::new(hosts::DATA_API);
+/// ```
 pub struct DataClient {
     http_client: HttpClient,
 }
@@ -15,15 +32,20 @@ impl DataClient {
     /// Create a new DataClient
     ///
     /// # Arguments
-    /// * `host` - The base URL for the data API (typically different from main CLOB API)
+    /// * `host` - The base URL for the data API, normally
+    ///   [`hosts::DATA_API`](crate::config::hosts::DATA_API), not the CLOB host
     pub fn new(host: impl Into<String>) -> Self {
         Self {
             http_client: HttpClient::new(host),
         }
     }
 
+    http_client_builders!();
+
     /// Get all positions for a user
     ///
+    /// Data API `GET /positions`.
+    ///
     /// # Arguments
     /// * `user` - The user's wallet address
     ///
@@ -34,8 +56,36 @@ impl DataClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Compute an account summary with per-position and aggregate PNL
+    ///
+    /// Fetches the user's positions, marks them at current midpoints from
+    /// `clob` and computes cost basis, value, and unrealized/realized PNL.
+    /// Positions come from the data API (`GET /positions`), midpoints from the
+    /// CLOB API (`POST /midpoints`), hence the extra client. Positions without
+    /// a midpoint (e.g. resolved markets) are marked at the data API's current
+    /// price.
+    ///
+    /// # Arguments
+    /// * `user` - The user's wallet address (the proxy wallet for proxy accounts)
+    /// * `clob` - Client for the CLOB API, used to fetch midpoints
+    pub async fn portfolio_pnl(&self, user: Address, clob: &ClobClient) -> Result<PortfolioPnl> {
+        let positions = self.get_positions(&user.to_checksum(None)).await?;
+        let token_ids: Vec<TokenId> = positions
+            .iter()
+            .map(|p| TokenId::new(p.asset.clone()))
+            .collect();
+        let prices = if token_ids.is_empty() {
+            HashMap::new()
+        } else {
+            clob.get_midpoints(&token_ids).await?
+        };
+        Ok(PortfolioPnl::from_positions(&positions, &prices))
+    }
+
     /// Get the total value of positions for a user
     ///
+    /// Data API `GET /value`.
+    ///
     /// # Arguments
     /// * `user` - The user's wallet address
     ///
@@ -48,6 +98,8 @@ impl DataClient {
 
     /// Get recent trades
     ///
+    /// Data API `GET /trades`.
+    ///
     /// # Arguments
     /// * `user` - User wallet address to filter trades
     /// * `params` - Optional query parameters (limit, offset, taker_only)
@@ -70,8 +122,36 @@ impl DataClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get recent trades in a market, across all users
+    ///
+    /// Data API `GET /trades`.
+    ///
+    /// The CLOB only serves trade history for the authenticated user
+    /// ([`TradingClient::get_trades`](crate::TradingClient::get_trades)); this
+    /// public endpoint returns every fill in the market, newest first, without
+    /// credentials. Page through older trades with `limit` and `offset`.
+    ///
+    /// # Arguments
+    /// * `condition_id` - The condition ID of the market
+    /// * `params` - Optional query parameters (limit, offset, taker_only)
+    pub async fn get_market_trades(
+        &self,
+        condition_id: &ConditionId,
+        params: Option<TradeQueryParams>,
+    ) -> Result<Vec<Trade>> {
+        let mut path = format!("/trades?market={}", condition_id.as_str());
+
+        if let Some(params) = params {
+            path.push_str(&params.to_query_string());
+        }
+
+        self.http_client.get(&path, None).await
+    }
+
     /// Get recent activity
     ///
+    /// Data API `GET /activity`.
+    ///
     /// # Arguments
     /// * `user` - User wallet address to filter activity
     /// * `params` - Optional query parameters (limit, offset, sort_by, sort_direction)
@@ -94,6 +174,8 @@ impl DataClient {
 
     /// Get closed positions
     ///
+    /// Data API `GET /closed-positions`.
+    ///
     /// # Arguments
     /// * `user` - User wallet address
     ///
@@ -104,3 +186,63 @@ impl DataClient {
         self.http_client.get(&path, None).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_portfolio_pnl() {
+        let data_mock = MockTransport::new().on(
+            "GET",
+            "/positions",
+            200,
+            r#"[{"proxyWallet": "0x1", "asset": "1", "conditionId": "0xc", "size": 100,
+                "avgPrice": 0.4, "initialValue": 40, "currentValue": 45, "cashPnl": 5,
+                "percentPnl": 12.5, "totalBought": 100, "realizedPnl": 2,
+                "percentRealizedPnl": 0, "curPrice": 0.45, "redeemable": false,
+                "mergeable": false, "title": "Will it rain?", "eventId": "1",
+                "outcome": "Yes", "outcomeIndex": 0, "oppositeOutcome": "No",
+                "oppositeAsset": "2", "endDate": "2030-01-01", "negativeRisk": false}]"#,
+        );
+        let clob_mock = MockTransport::new().on("POST", "/midpoints", 200, r#"{"1": "0.5"}"#);
+        let data = DataClient::new("https://data.example").with_transport(data_mock);
+        let clob = ClobClient::new("https://clob.example").with_transport(clob_mock);
+
+        let pnl = data
+            .portfolio_pnl(Address::repeat_byte(0x11), &clob)
+            .await
+            .unwrap();
+        assert_eq!(pnl.positions[0].mark_price, dec!(0.5));
+        assert_eq!(pnl.total_value, dec!(50));
+        assert_eq!(pnl.unrealized_pnl, dec!(10));
+        assert_eq!(pnl.total_pnl, dec!(12));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_trades() {
+        let mock = MockTransport::new().on(
+            "GET",
+            "/trades?market=0xc&limit=1&offset=10",
+            200,
+            r#"[{"proxyWallet": "0x1", "side": "BUY", "asset": "1", "conditionId": "0xc",
+                "size": 25, "price": 0.43, "timestamp": 1700000000, "title": "Will it rain?",
+                "slug": "will-it-rain", "icon": "", "eventSlug": "rain", "outcome": "Yes",
+                "outcomeIndex": 0, "name": "", "pseudonym": "", "bio": "",
+                "profileImage": "", "profileImageOptimized": "",
+                "transactionHash": "0xabc"}]"#,
+        );
+        let data = DataClient::new("https://data.example").with_transport(mock);
+
+        let params = TradeQueryParams::new().with_limit(1).with_offset(10);
+        let trades = data
+            .get_market_trades(&"0xc".into(), Some(params))
+            .await
+            .unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].price, dec!(0.43));
+        assert_eq!(trades[0].size, dec!(25));
+    }
+}
@@ -1,7 +1,7 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::http::HttpClient;
 use crate::request::GammaMarketParams;
-use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag};
+use crate::types::{GammaCategory, GammaEvent, GammaMarket, GammaSeries, GammaTag, NegRiskMarket};
 
 /// Client for Gamma API - Market discovery and metadata
 ///
@@ -54,6 +54,8 @@ impl GammaClient {
         }
     }
 
+    http_client_builders!();
+
     /// Get markets with optional filtering and pagination
     ///
     /// # Arguments
@@ -78,9 +80,7 @@ impl GammaClient {
     ///
     /// let markets = client.get_markets(Some(params)).await?;
     /// for market in markets {
-    ///     if let Some(q) = &market.question {
-    ///         println!("{}: {}", market.id, q);
-    ///     }
+    ///     println!("{}: {}", market.id, market.question);
     /// }
     /// # Ok(())
     /// # }
@@ -112,9 +112,7 @@ impl GammaClient {
     /// # async fn main() -> polymarket_rs::Result<()> {
     /// let client = GammaClient::new("https://gamma-api.polymarket.com");
     /// let market = client.get_market("0x123...").await?;
-    /// if let Some(q) = &market.question {
-    ///     println!("Market: {}", q);
-    /// }
+    /// println!("Market: {}", market.question);
     /// # Ok(())
     /// # }
     /// ```
@@ -224,6 +222,38 @@ impl GammaClient {
         self.http_client.get("/events", None).await
     }
 
+    /// Get events under a tag (e.g. "politics", "crypto")
+    ///
+    /// Tag filtering is only supported by the Gamma API, so this call targets the
+    /// Gamma host this client was created with (`/events?tag_slug=...`). Each event
+    /// includes its markets. To list markets directly, use [`get_markets`](Self::get_markets)
+    /// with [`GammaMarketParams::with_tag_id`].
+    ///
+    /// # Arguments
+    /// * `tag_slug` - The tag slug, as returned by [`get_tags`](Self::get_tags)
+    ///
+    /// # Returns
+    /// A list of events tagged with `tag_slug`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let events = client.get_events_by_tag("politics").await?;
+    /// for event in events {
+    ///     println!("{}: {} markets", event.title, event.markets.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_events_by_tag(&self, tag_slug: &str) -> Result<Vec<GammaEvent>> {
+        let path = format!("/events?tag_slug={}", tag_slug);
+        self.http_client.get(&path, None).await
+    }
+
     /// Get a specific event by its ID
     ///
     /// # Arguments
@@ -249,6 +279,35 @@ impl GammaClient {
         self.http_client.get(&path, None).await
     }
 
+    /// Get the neg risk market behind a multi-outcome event
+    ///
+    /// Maps the event's outcome markets to their Yes/No token IDs under the
+    /// shared neg risk market ID.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if the event is not a neg risk event.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use polymarket_rs::client::GammaClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> polymarket_rs::Result<()> {
+    /// let client = GammaClient::new("https://gamma-api.polymarket.com");
+    /// let market = client.get_neg_risk_market("63806").await?;
+    /// for outcome in &market.outcomes {
+    ///     println!("{}: YES={}", outcome.question, outcome.yes_token_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_neg_risk_market(&self, event_id: &str) -> Result<NegRiskMarket> {
+        let event = self.get_event_by_id(event_id).await?;
+        NegRiskMarket::from_event(&event).ok_or_else(|| {
+            Error::InvalidParameter(format!("event {} is not a neg risk event", event_id))
+        })
+    }
+
     /// Get all series
     ///
     /// Series are groupings of related events and markets. This endpoint returns
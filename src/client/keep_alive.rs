@@ -0,0 +1,463 @@
+use crate::error::{Error, Result};
+use crate::types::{
+    CreateOrderOptions, ExtraOrderArgs, OrderArgs, OrderId, OrderStatus, OrderType,
+};
+use crate::utils::get_current_unix_time_secs;
+use crate::TradingClient;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+/// Handle to a refresh loop started by [`TradingClient::keep_alive_order`]
+///
+/// Dropping the handle stops the loop like [`stop`](Self::stop), without
+/// waiting for it.
+pub struct KeepAliveHandle {
+    current: Arc<Mutex<OrderId>>,
+    stop: Option<oneshot::Sender<()>>,
+    task: JoinHandle<Result<()>>,
+}
+
+impl KeepAliveHandle {
+    /// ID of the order currently kept alive
+    pub fn current_order_id(&self) -> OrderId {
+        self.current
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Whether the loop has ended, because the order is no longer live or
+    /// because of an error
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Stop refreshing and wait for the loop to end
+    ///
+    /// The current order is left on the book until it expires. Returns the
+    /// error that ended the loop, if it ended on its own before being stopped.
+    pub async fn stop(mut self) -> Result<()> {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        match (&mut self.task).await {
+            Ok(result) => result,
+            // The task is never aborted, so it can only have panicked
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+}
+
+impl TradingClient {
+    /// Keep a GTD order on the book by re-posting it before it expires
+    ///
+    /// Spawns a task that waits until `refresh_before` ahead of the order's
+    /// expiration, cancels the old order, posts a fresh GTD order from
+    /// `order_args`, `extras` and `options`, and repeats with the new order.
+    /// Pass the same `extras` and `options` the original order was created
+    /// with, so refreshed orders only differ in size and expiration. The old
+    /// order is canceled before the new one is posted, so the two never rest
+    /// on the book together.
+    ///
+    /// On each refresh the order is fetched again and only the size still
+    /// open on it is re-posted, so fills made while waiting are not
+    /// re-posted. The loop ends when the order is no longer live (filled,
+    /// canceled or expired), or with the first error from fetching,
+    /// canceling or posting an order, which [`KeepAliveHandle::stop`]
+    /// returns. If the old order can't be canceled, nothing is posted.
+    ///
+    /// `order_args` must set [`OrderArgs::expires_in`], the lifetime of each
+    /// refreshed order, and it must be longer than `refresh_before`.
+    ///
+    /// ```no_run
+    /// # use polymarket_rs::{CreateOrderOptions, OrderArgs, OrderId, Side, TradingClient};
+    /// # use rust_decimal_macros::dec;
+    /// # use std::sync::Arc;
+    /// # use std::time::Duration;
+    /// # async fn example(client: Arc<TradingClient>, order_id: OrderId) -> polymarket_rs::Result<()> {
+    /// let args = OrderArgs::new("1234", dec!(0.45), dec!(100), Side::Buy)
+    ///     .expires_in(Duration::from_secs(3600));
+    /// let options = CreateOrderOptions::new().tick_size(dec!(0.01)).neg_risk(false);
+    ///
+    /// let refresh_before = Duration::from_secs(120);
+    /// let handle = client.keep_alive_order(order_id, args, None, options, refresh_before)?;
+    /// // ...
+    /// handle.stop().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `order_args` has no lifetime or
+    /// one not longer than `refresh_before`.
+    pub fn keep_alive_order(
+        self: Arc<Self>,
+        order_id: OrderId,
+        order_args: OrderArgs,
+        extras: Option<ExtraOrderArgs>,
+        options: CreateOrderOptions,
+        refresh_before: Duration,
+    ) -> Result<KeepAliveHandle> {
+        match order_args.expires_in {
+            Some(lifetime) if lifetime > refresh_before => {}
+            Some(lifetime) => {
+                return Err(Error::InvalidParameter(format!(
+                    "Order lifetime {:?} must be longer than refresh_before {:?}",
+                    lifetime, refresh_before
+                )))
+            }
+            None => {
+                return Err(Error::InvalidParameter(
+                    "order_args must set expires_in to keep an order alive".to_string(),
+                ))
+            }
+        }
+
+        let current = Arc::new(Mutex::new(order_id));
+        let (stop_tx, stop_rx) = oneshot::channel();
+        let task = tokio::spawn(keep_alive(
+            self,
+            Arc::clone(&current),
+            order_args,
+            extras,
+            options,
+            refresh_before,
+            stop_rx,
+        ));
+
+        Ok(KeepAliveHandle {
+            current,
+            stop: Some(stop_tx),
+            task,
+        })
+    }
+}
+
+/// Refresh loop behind [`TradingClient::keep_alive_order`]
+async fn keep_alive(
+    client: Arc<TradingClient>,
+    current: Arc<Mutex<OrderId>>,
+    mut order_args: OrderArgs,
+    extras: Option<ExtraOrderArgs>,
+    options: CreateOrderOptions,
+    refresh_before: Duration,
+    mut stop: oneshot::Receiver<()>,
+) -> Result<()> {
+    let mut order_id = current.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let mut order = client.get_order(&order_id).await?;
+    loop {
+        if order.status != OrderStatus::Live {
+            log::info!(
+                "Order {} is {}, no longer keeping it alive",
+                order_id,
+                order.status
+            );
+            return Ok(());
+        }
+        if order.expiration == 0 {
+            return Err(Error::InvalidOrder(format!(
+                "Order {} has no expiration to refresh before",
+                order_id
+            )));
+        }
+
+        let now = get_current_unix_time_secs()?;
+        let wait = Duration::from_secs(order.expiration.saturating_sub(now))
+            .saturating_sub(refresh_before);
+        tokio::select! {
+            // Stopped, or the handle was dropped
+            _ = &mut stop => return Ok(()),
+            _ = tokio::time::sleep(wait) => {}
+        }
+
+        // The order may have been filled while waiting
+        order = client.get_order(&order_id).await?;
+        let remaining = order.remaining_size();
+        if order.status != OrderStatus::Live || remaining.is_zero() {
+            log::info!(
+                "Order {} is {}, no longer keeping it alive",
+                order_id,
+                order.status
+            );
+            return Ok(());
+        }
+
+        let canceled = client.cancel(&order_id).await?;
+        if !canceled.canceled.contains(&order_id) {
+            return Err(Error::InvalidOrder(format!(
+                "Order {} could not be canceled for a refresh: {}",
+                order_id, canceled.not_canceled
+            )));
+        }
+
+        order_args.size = remaining;
+        let response = client
+            .create_and_post_order(
+                &order_args,
+                None,
+                extras.as_ref(),
+                options.clone(),
+                OrderType::Gtd,
+            )
+            .await?;
+        if !response.success {
+            return Err(Error::InvalidOrder(format!(
+                "Refreshed order for {} was rejected: {}",
+                order_id, response.error_msg
+            )));
+        }
+
+        order_id = response.order_id;
+        *current.lock().unwrap_or_else(|e| e.into_inner()) = order_id.clone();
+        order = client.get_order(&order_id).await?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::types::ApiCreds;
+    use crate::OrderBuilder;
+    use crate::Side;
+    use alloy_signer_local::PrivateKeySigner;
+    use rust_decimal_macros::dec;
+
+    fn open_order(id: &str, status: &str, expiration: u64, size_matched: u32) -> String {
+        format!(
+            r#"{{"id": "{}", "associate_trades": [], "status": "{}", "market": "0x2",
+                "original_size": "10", "outcome": "Yes", "maker_address": "0x3",
+                "owner": "key", "price": "0.5", "side": "BUY", "size_matched": "{}",
+                "asset_id": "1234", "expiration": "{}", "order_type": "GTD",
+                "created_at": "1"}}"#,
+            id, status, size_matched, expiration
+        )
+    }
+
+    fn client(mock: &MockTransport) -> Arc<TradingClient> {
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let order_builder = OrderBuilder::new(signer.clone(), None, None);
+        Arc::new(
+            TradingClient::new("https://clob.example", signer, 137, creds, order_builder)
+                .with_transport(mock.clone()),
+        )
+    }
+
+    fn args() -> (OrderArgs, CreateOrderOptions) {
+        let args = OrderArgs::new("1234", dec!(0.5), dec!(10), Side::Buy)
+            .expires_in(Duration::from_secs(3600));
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        (args, options)
+    }
+
+    fn calls(mock: &MockTransport) -> Vec<String> {
+        mock.requests()
+            .iter()
+            .map(|r| format!("{} {}", r.method, r.url))
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_keep_alive_cancels_and_reposts() {
+        // Due for a refresh 3480 seconds from now
+        let expiration = get_current_unix_time_secs().unwrap() + 3600;
+        let mock = MockTransport::new()
+            .on(
+                "GET",
+                "/data/order/0x1",
+                200,
+                open_order("0x1", "LIVE", expiration, 4),
+            )
+            .on(
+                "GET",
+                "/data/order/0x2",
+                200,
+                open_order("0x2", "MATCHED", 0, 3),
+            )
+            .on(
+                "POST",
+                "/order",
+                200,
+                r#"{"orderID": "0x2", "status": "live", "success": true}"#,
+            )
+            .on("DELETE", "/order", 200, r#"{"canceled": ["0x1"]}"#);
+        let (args, options) = args();
+        let mut extras = ExtraOrderArgs::default().fee_rate_bps(25);
+        extras.taker = "0x0000000000000000000000000000000000000007".to_string();
+
+        let handle = client(&mock)
+            .keep_alive_order(
+                OrderId::new("0x1"),
+                args,
+                Some(extras),
+                options,
+                Duration::from_secs(120),
+            )
+            .unwrap();
+        // Let the loop fetch the order and start waiting
+        tokio::task::yield_now().await;
+        assert_eq!(calls(&mock), ["GET https://clob.example/data/order/0x1"]);
+
+        // Not due yet
+        tokio::time::advance(Duration::from_secs(3470)).await;
+        assert_eq!(calls(&mock).len(), 1);
+        assert_eq!(handle.current_order_id(), OrderId::new("0x1"));
+
+        // More of the order fills while the loop waits
+        mock.clone().on(
+            "GET",
+            "/data/order/0x1",
+            200,
+            open_order("0x1", "LIVE", expiration, 7),
+        );
+        tokio::time::advance(Duration::from_secs(20)).await;
+        // Let the woken loop run; the mock transport never blocks
+        tokio::task::yield_now().await;
+        assert!(handle.is_finished());
+        assert_eq!(handle.current_order_id(), OrderId::new("0x2"));
+        handle.stop().await.unwrap();
+
+        assert_eq!(
+            calls(&mock),
+            [
+                "GET https://clob.example/data/order/0x1",
+                "GET https://clob.example/data/order/0x1",
+                "DELETE https://clob.example/order",
+                "POST https://clob.example/order",
+                "GET https://clob.example/data/order/0x2",
+            ]
+        );
+        let requests = mock.requests();
+        assert!(requests[2].body.as_deref().unwrap().contains("0x1"));
+        let body: serde_json::Value =
+            serde_json::from_str(requests[3].body.as_deref().unwrap()).unwrap();
+        // Only the remainder open after the latest fills is re-posted, with
+        // the original extras
+        assert_eq!(body["order"]["takerAmount"], "3000000");
+        assert_eq!(body["order"]["feeRateBps"], "25");
+        assert_eq!(
+            body["order"]["taker"],
+            "0x0000000000000000000000000000000000000007"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_keep_alive_does_not_repost_when_cancel_fails() {
+        let expiration = get_current_unix_time_secs().unwrap() + 3600;
+        let mock = MockTransport::new()
+            .on(
+                "GET",
+                "/data/order/0x1",
+                200,
+                open_order("0x1", "LIVE", expiration, 4),
+            )
+            .on(
+                "DELETE",
+                "/order",
+                200,
+                r#"{"canceled": [], "not_canceled": {"0x1": "order not found"}}"#,
+            );
+        let (args, options) = args();
+
+        let handle = client(&mock)
+            .keep_alive_order(
+                OrderId::new("0x1"),
+                args,
+                None,
+                options,
+                Duration::from_secs(120),
+            )
+            .unwrap();
+        tokio::task::yield_now().await;
+        tokio::time::advance(Duration::from_secs(3490)).await;
+        tokio::task::yield_now().await;
+        assert!(handle.is_finished());
+        assert_eq!(handle.current_order_id(), OrderId::new("0x1"));
+        assert!(matches!(handle.stop().await, Err(Error::InvalidOrder(_))));
+        assert!(!calls(&mock).iter().any(|c| c.starts_with("POST")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_keep_alive_stops_when_filled_while_waiting() {
+        let expiration = get_current_unix_time_secs().unwrap() + 3600;
+        let mock = MockTransport::new().on(
+            "GET",
+            "/data/order/0x1",
+            200,
+            open_order("0x1", "LIVE", expiration, 4),
+        );
+        let (args, options) = args();
+
+        let handle = client(&mock)
+            .keep_alive_order(
+                OrderId::new("0x1"),
+                args,
+                None,
+                options,
+                Duration::from_secs(120),
+            )
+            .unwrap();
+        tokio::task::yield_now().await;
+        mock.clone().on(
+            "GET",
+            "/data/order/0x1",
+            200,
+            open_order("0x1", "MATCHED", expiration, 10),
+        );
+        tokio::time::advance(Duration::from_secs(3490)).await;
+        tokio::task::yield_now().await;
+        assert!(handle.is_finished());
+        handle.stop().await.unwrap();
+        assert_eq!(
+            calls(&mock),
+            [
+                "GET https://clob.example/data/order/0x1",
+                "GET https://clob.example/data/order/0x1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keep_alive_stops_on_request() {
+        let expiration = get_current_unix_time_secs().unwrap() + 3600;
+        let mock = MockTransport::new().on(
+            "GET",
+            "/data/order/0x1",
+            200,
+            open_order("0x1", "LIVE", expiration, 4),
+        );
+        let (args, options) = args();
+
+        let handle = client(&mock)
+            .keep_alive_order(
+                OrderId::new("0x1"),
+                args,
+                None,
+                options,
+                Duration::from_secs(120),
+            )
+            .unwrap();
+        handle.stop().await.unwrap();
+        assert!(mock.requests().len() <= 1);
+    }
+
+    #[test]
+    fn test_keep_alive_requires_lifetime() {
+        let mock = MockTransport::new();
+        let (args, options) = args();
+        let result = client(&mock).keep_alive_order(
+            OrderId::new("0x1"),
+            args.expires_in(Duration::from_secs(60)),
+            None,
+            options,
+            Duration::from_secs(120),
+        );
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+    }
+}
@@ -1,11 +1,122 @@
+//! REST API clients
+//!
+//! All clients are `Send + Sync` (signers must implement [`EthSigner`](crate::EthSigner),
+//! which requires both), so a single instance can be wrapped in an `Arc` and
+//! shared across tokio tasks. Methods take `&self`; only credential updates such
+//! as [`AuthenticatedClient::set_api_creds`] need exclusive access.
+
+/// Builder methods configuring a client's `http_client` field
+///
+/// Expanded inside the `impl` block of every REST client, so the options and
+/// their docs are shared.
+macro_rules! http_client_builders {
+    () => {
+        /// Replace the HTTP transport used to send requests
+        ///
+        /// Useful for injecting a [`MockTransport`](crate::transport::MockTransport)
+        /// (`test-util` feature) in tests.
+        pub fn with_transport(
+            mut self,
+            transport: impl $crate::transport::Transport + 'static,
+        ) -> Self {
+            self.http_client = self.http_client.with_transport(transport);
+            self
+        }
+
+        /// Set headers sent with every request (e.g. `User-Agent`, tracing headers)
+        ///
+        /// Auth headers are merged in on top and are never overwritten.
+        pub fn with_default_headers(mut self, headers: ::reqwest::header::HeaderMap) -> Self {
+            self.http_client = self.http_client.with_default_headers(headers);
+            self
+        }
+
+        /// Observe every request and response, e.g. to debug signatures
+        ///
+        /// See [`interceptor`](crate::interceptor) for redacting the auth headers.
+        pub fn with_interceptor(
+            mut self,
+            interceptor: ::std::sync::Arc<dyn $crate::interceptor::HttpInterceptor>,
+        ) -> Self {
+            self.http_client = self.http_client.with_interceptor(interceptor);
+            self
+        }
+
+        /// Prefix every request path, for APIs served behind a path-prefixing gateway
+        ///
+        /// E.g. with prefix `/clob`, orders are posted to `/clob/order`. L2 signatures
+        /// still cover the unprefixed path, which is what the API sees once the
+        /// gateway strips the prefix.
+        pub fn with_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+            self.http_client = self.http_client.with_path_prefix(prefix);
+            self
+        }
+
+        /// Limit the number of requests in flight at once
+        ///
+        /// Requests beyond `max` wait for an earlier one to complete instead of
+        /// being sent, which keeps large fan-outs (e.g. many concurrent
+        /// order book fetches) under the API's rate limits. Clones of the client
+        /// share the limit. By default the number of concurrent requests is
+        /// unlimited. A `max` of 0 is treated as 1.
+        pub fn with_max_concurrency(mut self, max: usize) -> Self {
+            self.http_client = self.http_client.with_max_concurrency(max);
+            self
+        }
+    };
+}
+
 mod authenticated;
 mod clob;
 mod data;
 mod gamma;
+mod keep_alive;
 mod trading;
 
 pub use authenticated::AuthenticatedClient;
 pub use clob::ClobClient;
 pub use data::DataClient;
 pub use gamma::GammaClient;
+pub use keep_alive::KeepAliveHandle;
 pub use trading::TradingClient;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::orders::OrderBuilder;
+    use crate::websocket::{MarketWsClient, UserWsClient};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_clients_are_send_sync() {
+        assert_send_sync::<AuthenticatedClient>();
+        assert_send_sync::<ClobClient>();
+        assert_send_sync::<DataClient>();
+        assert_send_sync::<GammaClient>();
+        assert_send_sync::<TradingClient>();
+        assert_send_sync::<OrderBuilder>();
+        assert_send_sync::<MarketWsClient>();
+        assert_send_sync::<UserWsClient>();
+    }
+
+    #[tokio::test]
+    async fn test_client_shared_across_tasks() {
+        use crate::transport::MockTransport;
+        use std::sync::Arc;
+
+        let mock = MockTransport::new().on("GET", "/", 200, r#""OK""#);
+        let client = Arc::new(ClobClient::new("https://clob.example").with_transport(mock.clone()));
+
+        let tasks: Vec<_> = (0..4)
+            .map(|_| {
+                let client = Arc::clone(&client);
+                tokio::spawn(async move { client.get_ok().await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+        assert_eq!(mock.requests().len(), 4);
+    }
+}
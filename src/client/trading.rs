@@ -1,12 +1,30 @@
-use crate::error::Result;
+use crate::client::authenticated::balance_allowance_request;
+use crate::client::ClobClient;
+use crate::config::chains;
+use crate::error::{Error, Result};
 use crate::http::{create_l2_headers, HttpClient};
-use crate::orders::{calculate_market_price, OrderBuilder};
-use crate::signing::EthSigner;
+use crate::orders::{
+    available_liquidity, calculate_market_price, decimal_to_token_u64, increment_nonce_call,
+    token_u64_to_decimal, ExchangeCall, OrderBuilder, USDC_DECIMALS,
+};
+use crate::signing::{order_request_hash, recover_order_signer, EthSigner, ExchangeVariant};
 use crate::types::{
-    ApiCreds, CancelOrdersResponse, CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OpenOrder,
-    OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId, OrderType,
-    PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TradeParams,
+    ApiCreds, AssetType, BalanceAllowance, BalanceAllowanceParams, CancelOrdersResponse, ClobTrade,
+    ClobTradesResponse, CreateOrderOptions, ExportFormat, ExtraOrderArgs, MarketOrderArgs,
+    OpenOrder, OpenOrderParams, OpenOrdersResponse, OrderArgs, OrderBookSummary, OrderId,
+    OrderType, PostOrder, PostOrderArgs, PostOrderResponse, Side, SignedOrderRequest, TokenId,
+    TradeParams,
 };
+use alloy_primitives::{Address, U256};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::io::Write;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Header carrying the key passed to [`TradingClient::post_order_with_idempotency_key`]
+const IDEMPOTENCY_KEY_HEADER: &str = "X-Idempotency-Key";
 
 /// Client for trading operations
 ///
@@ -18,9 +36,16 @@ pub struct TradingClient {
     chain_id: u64,
     api_creds: ApiCreds,
     order_builder: OrderBuilder,
+    idempotency_cache: Mutex<HashMap<String, PostOrderResponse>>,
+    cancel_batch_size: usize,
+    check_balance: bool,
+    signature_diagnostics: bool,
 }
 
 impl TradingClient {
+    /// Default number of order IDs per [`cancel_orders`](Self::cancel_orders) request
+    pub const DEFAULT_CANCEL_BATCH_SIZE: usize = 100;
+
     /// Create a new TradingClient
     ///
     /// # Arguments
@@ -35,21 +60,89 @@ impl TradingClient {
         chain_id: u64,
         api_creds: ApiCreds,
         order_builder: OrderBuilder,
+    ) -> Self {
+        Self::from_http_client(
+            HttpClient::new(host),
+            signer,
+            chain_id,
+            api_creds,
+            order_builder,
+        )
+    }
+
+    /// Create a TradingClient on an already configured HTTP client
+    pub(crate) fn from_http_client(
+        http_client: HttpClient,
+        signer: impl EthSigner + 'static,
+        chain_id: u64,
+        api_creds: ApiCreds,
+        order_builder: OrderBuilder,
     ) -> Self {
         Self {
-            http_client: HttpClient::new(host),
+            http_client,
             signer: Box::new(signer),
             chain_id,
             api_creds,
             order_builder,
+            idempotency_cache: Mutex::new(HashMap::new()),
+            cancel_batch_size: Self::DEFAULT_CANCEL_BATCH_SIZE,
+            check_balance: false,
+            signature_diagnostics: false,
         }
     }
 
+    http_client_builders!();
+
+    /// Set the maximum number of order IDs sent per request by
+    /// [`cancel_orders`](Self::cancel_orders)
+    ///
+    /// Defaults to [`DEFAULT_CANCEL_BATCH_SIZE`](Self::DEFAULT_CANCEL_BATCH_SIZE).
+    /// A size of 0 is treated as 1.
+    pub fn with_cancel_batch_size(mut self, size: usize) -> Self {
+        self.cancel_batch_size = size.max(1);
+        self
+    }
+
+    /// Check the balance before posting in [`create_and_post_order`](Self::create_and_post_order)
+    ///
+    /// When enabled, the order's maker amount (USDC for buys, outcome tokens
+    /// for sells) is compared against the funder's balance, and against the
+    /// exchange's allowance when the API reports one, before the order is
+    /// posted. Orders that aren't covered fail with
+    /// [`Error::InvalidOrder`](crate::Error::InvalidOrder) stating the
+    /// shortfall instead of being rejected by the server.
+    ///
+    /// Costs an extra request per order, so it's off by default. Balance
+    /// already locked by open orders isn't subtracted, so the server can still
+    /// reject an order that passes.
+    pub fn with_balance_check(mut self, enabled: bool) -> Self {
+        self.check_balance = enabled;
+        self
+    }
+
+    /// Explain signature rejections of posted orders
+    ///
+    /// When enabled, a post rejected by the server with an error mentioning the
+    /// signature fails with [`Error::Signing`](crate::Error::Signing) instead
+    /// of [`Error::Api`](crate::Error::Api). The message adds the locally
+    /// computed order hash, the address recovered from the signature and,
+    /// when the signature matches another chain or exchange contract, which
+    /// one, pointing at a wrong `neg_risk` flag or chain ID.
+    ///
+    /// Meant for debugging; it keeps a copy of every posted order, so it's
+    /// off by default.
+    pub fn with_signature_diagnostics(mut self, enabled: bool) -> Self {
+        self.signature_diagnostics = enabled;
+        self
+    }
+
     /// Create a limit order (local operation, not posted)
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional GTD expiration in Unix seconds (defaults to 0 = no
+    ///   expiration); the exchange's 60 second security buffer is added automatically.
+    ///   Leave unset when using [`OrderArgs::expires_in`]
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     pub fn create_order(
@@ -74,6 +167,15 @@ impl TradingClient {
     /// * `order_book` - The order book to calculate price from
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
+    ///
+    /// # Errors
+    /// If the book can't fill the full amount, returns
+    /// [`Error::InsufficientLiquidity`](crate::Error::InsufficientLiquidity) with
+    /// the fillable amount (USDC for buys, shares for sells, like
+    /// [`MarketOrderArgs::amount`]), unless [`MarketOrderArgs::allow_partial`] is set, in
+    /// which case the order is sized down to the available liquidity. Missing
+    /// `tick_size` or `neg_risk` options fail with
+    /// [`Error::MissingField`](crate::Error::MissingField) first.
     pub fn create_market_order(
         &self,
         order_args: &MarketOrderArgs,
@@ -81,6 +183,8 @@ impl TradingClient {
         extras: Option<&ExtraOrderArgs>,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
+        // Report missing options before any liquidity error from the book
+        options.required()?;
         let default_extras = ExtraOrderArgs::default();
         let extras = extras.unwrap_or(&default_extras);
 
@@ -90,6 +194,25 @@ impl TradingClient {
             Side::Sell => &order_book.bids,
         };
 
+        // Size down to what the book can fill if partial fills are allowed,
+        // comparing in USDC for buys and shares for sells
+        let available = available_liquidity(book_side, order_args.side);
+        let sized_args;
+        let order_args = if order_args.amount <= available {
+            order_args
+        } else if order_args.allow_partial && !available.is_zero() {
+            sized_args = MarketOrderArgs {
+                amount: available,
+                ..order_args.clone()
+            };
+            &sized_args
+        } else {
+            return Err(Error::InsufficientLiquidity {
+                requested: order_args.amount,
+                available,
+            });
+        };
+
         // Calculate market price from order book
         let price = calculate_market_price(book_side, order_args.amount, order_args.side)?;
 
@@ -99,6 +222,13 @@ impl TradingClient {
 
     /// Post an order to the exchange
     ///
+    /// Orders created with [`ExtraOrderArgs::post_only`] are sent with the
+    /// exchange's native `postOnly` flag and must be GTC or GTD.
+    ///
+    /// The order is owned by this client's API key; see
+    /// [`post_order_with_owner`](Self::post_order_with_owner) to post it under
+    /// another one.
+    ///
     /// # Arguments
     /// * `order` - The signed order to post
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
@@ -107,19 +237,201 @@ impl TradingClient {
         order: SignedOrderRequest,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
-        let owner = self.api_creds.api_key.clone();
-        let post_order = PostOrder::new(order, owner, order_type);
+        self.post_order_with_owner(order, order_type, &self.api_creds.api_key)
+            .await
+    }
 
-        let headers = create_l2_headers(
+    /// Post an order owned by another API key
+    ///
+    /// The `owner` of a posted order is the API key of the account the order
+    /// is booked under, independent of the key whose L2 headers authenticate
+    /// the request. [`post_order`](Self::post_order) uses this client's own
+    /// key, which is right for single-account setups. Sub-account or
+    /// delegated setups that post for another account pass that account's
+    /// key here.
+    ///
+    /// The exchange rejects orders whose owner it doesn't associate with the
+    /// authenticating key, often without a specific error message, so
+    /// double-check the owner when orders from a delegated setup go missing.
+    ///
+    /// # Arguments
+    /// * `order` - The signed order to post
+    /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    /// * `owner` - API key owning the order
+    pub async fn post_order_with_owner(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        owner: &str,
+    ) -> Result<PostOrderResponse> {
+        self.send_order(order, order_type, owner, None).await
+    }
+
+    /// Post an order, sending `idempotency_key` as a header if given
+    async fn send_order(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        owner: &str,
+        idempotency_key: Option<&str>,
+    ) -> Result<PostOrderResponse> {
+        let token_id = order.token_id.clone();
+        let diagnostic_order = self.signature_diagnostics.then(|| order.clone());
+        let post_order = PostOrder::new(order, owner.to_string(), order_type);
+        post_order.validate()?;
+
+        let mut headers = create_l2_headers(
             &self.signer,
             &self.api_creds,
             "POST",
             "/order",
             Some(&post_order),
         )?;
+        if let Some(key) = idempotency_key {
+            headers.insert(IDEMPOTENCY_KEY_HEADER, key.to_string());
+        }
         self.http_client
             .post("/order", &post_order, Some(headers))
             .await
+            .map_err(|e| match (e, &diagnostic_order) {
+                (Error::Api { status, message }, Some(order))
+                    if (400..500).contains(&status)
+                        && message.to_ascii_lowercase().contains("signature") =>
+                {
+                    Error::Signing(format!("{} ({})", message, self.diagnose_signature(order)))
+                }
+                (e, _) => e.into_market_closed(Some(&token_id)),
+            })
+    }
+
+    /// Describe what the signature of `order` recovers to, for
+    /// [`with_signature_diagnostics`](Self::with_signature_diagnostics)
+    fn diagnose_signature(&self, order: &SignedOrderRequest) -> String {
+        let mut details = format!(
+            "signer {}, maker {}, signature type {}",
+            order.signer, order.maker, order.signature_type
+        );
+
+        let expected = match Address::from_str(&order.signer) {
+            Ok(signer) => signer,
+            Err(e) => {
+                details.push_str(&format!("; invalid signer address: {}", e));
+                return details;
+            }
+        };
+        if order.signature_type == 0 && !order.maker.eq_ignore_ascii_case(&order.signer) {
+            details.push_str("; EOA orders (signature type 0) need maker == signer");
+        }
+
+        let mut matched = None;
+        let other_chains = [chains::POLYGON_MAINNET, chains::POLYGON_AMOY_TESTNET]
+            .into_iter()
+            .filter(|&chain_id| chain_id != self.chain_id);
+        for chain_id in std::iter::once(self.chain_id).chain(other_chains) {
+            for exchange in [ExchangeVariant::Standard, ExchangeVariant::NegRisk] {
+                let Ok(contract) = exchange.exchange_address(chain_id) else {
+                    continue;
+                };
+                let (Ok(hash), Ok(recovered)) = (
+                    order_request_hash(order, chain_id, contract),
+                    recover_order_signer(order, chain_id, contract),
+                ) else {
+                    continue;
+                };
+                if chain_id == self.chain_id {
+                    details.push_str(&format!(
+                        "; {:?} exchange: hash {}, recovers {}",
+                        exchange, hash, recovered
+                    ));
+                }
+                if recovered == expected && matched.is_none() {
+                    matched = Some((chain_id, exchange));
+                }
+            }
+        }
+
+        match matched {
+            Some((chain_id, exchange)) => details.push_str(&format!(
+                "; signature is valid for the {:?} exchange on chain {}",
+                exchange, chain_id
+            )),
+            None => details.push_str(
+                "; signature doesn't recover the signer for any known exchange, \
+                 check the signing key and order fields",
+            ),
+        }
+        details
+    }
+
+    /// Post an order at most once per idempotency key
+    ///
+    /// Makes retries of a `post_order` call safe: once a post with `idempotency_key`
+    /// succeeds, later calls with the same key return the stored response
+    /// without contacting the exchange. Failed posts are not stored, so they
+    /// can be retried with the same key.
+    ///
+    /// The key is also sent as an `X-Idempotency-Key` header for proxies and
+    /// logging. Polymarket does not document support for this header, so the
+    /// deduplication is client-side only: it covers sequential retries on this
+    /// client instance, not concurrent calls with the same key or other
+    /// processes. The header is not part of the L2 signature, which only covers
+    /// timestamp, method, path and body. Independently, the exchange rejects a
+    /// signed order it has already seen, so resubmitting the *same* signed
+    /// order never creates a second one.
+    ///
+    /// Apart from the key, the order is posted like [`post_order`](Self::post_order).
+    ///
+    /// Stored responses are kept until [`clear_idempotency_keys`](Self::clear_idempotency_keys)
+    /// is called.
+    pub async fn post_order_with_idempotency_key(
+        &self,
+        order: SignedOrderRequest,
+        order_type: OrderType,
+        idempotency_key: impl Into<String>,
+    ) -> Result<PostOrderResponse> {
+        let idempotency_key = idempotency_key.into();
+        if let Some(response) = self.idempotency_cache().get(&idempotency_key) {
+            return Ok(response.clone());
+        }
+
+        let response = self
+            .send_order(
+                order,
+                order_type,
+                &self.api_creds.api_key,
+                Some(&idempotency_key),
+            )
+            .await?;
+
+        self.idempotency_cache()
+            .insert(idempotency_key, response.clone());
+        Ok(response)
+    }
+
+    /// Forget all responses stored by [`post_order_with_idempotency_key`](Self::post_order_with_idempotency_key)
+    pub fn clear_idempotency_keys(&self) {
+        self.idempotency_cache().clear();
+    }
+
+    fn idempotency_cache(&self) -> std::sync::MutexGuard<'_, HashMap<String, PostOrderResponse>> {
+        self.idempotency_cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Post a raw order body to the exchange
+    ///
+    /// **Advanced / unstable.** This is an escape hatch for order bodies that
+    /// [`PostOrder`] does not model yet (e.g. newly added server fields). The body
+    /// is sent to `/order` as-is and signed with L2 headers; no validation is
+    /// performed. Prefer [`post_order`](Self::post_order) whenever possible.
+    ///
+    /// # Arguments
+    /// * `body` - The complete JSON body, including `order`, `owner` and `orderType`
+    pub async fn post_order_raw(&self, body: serde_json::Value) -> Result<PostOrderResponse> {
+        let headers =
+            create_l2_headers(&self.signer, &self.api_creds, "POST", "/order", Some(&body))?;
+        self.http_client.post("/order", &body, Some(headers)).await
     }
 
     /// Post multiple orders to the exchange
@@ -147,6 +459,9 @@ impl TradingClient {
             .iter()
             .map(|arg| PostOrder::new(arg.order.clone(), owner.clone(), arg.order_type))
             .collect();
+        for post_order in &post_orders {
+            post_order.validate()?;
+        }
 
         let headers = create_l2_headers(
             &self.signer,
@@ -159,6 +474,7 @@ impl TradingClient {
         self.http_client
             .post("/orders", &post_orders, Some(headers))
             .await
+            .map_err(|e| e.into_market_closed(None))
     }
 
     /// Create and post an order in one step
@@ -167,10 +483,15 @@ impl TradingClient {
     ///
     /// # Arguments
     /// * `order_args` - Order arguments (token_id, price, size, side)
-    /// * `expiration` - Optional expiration timestamp (defaults to 0 = no expiration)
+    /// * `expiration` - Optional GTD expiration in Unix seconds (defaults to 0 = no
+    ///   expiration); the exchange's 60 second security buffer is added automatically.
+    ///   Leave unset when using [`OrderArgs::expires_in`]
     /// * `extras` - Optional extra order parameters (defaults to ExtraOrderArgs::default())
     /// * `options` - Order options (tick_size, neg_risk must be provided)
     /// * `order_type` - The order type (GTC, FOK, FAK, GTD)
+    ///
+    /// With [`with_balance_check`](Self::with_balance_check), the balance is
+    /// checked between creating and posting the order.
     pub async fn create_and_post_order(
         &self,
         order_args: &OrderArgs,
@@ -179,10 +500,123 @@ impl TradingClient {
         options: CreateOrderOptions,
         order_type: OrderType,
     ) -> Result<PostOrderResponse> {
+        let (_, neg_risk) = options.required()?;
+        let exchange = ExchangeVariant::from_neg_risk(neg_risk);
         let order = self.create_order(order_args, expiration, extras, options)?;
+        if self.check_balance {
+            self.ensure_balance_covers(&order, exchange).await?;
+        }
         self.post_order(order, order_type).await
     }
 
+    /// Fail with the shortfall if the funder can't cover the order's maker amount
+    async fn ensure_balance_covers(
+        &self,
+        order: &SignedOrderRequest,
+        exchange: ExchangeVariant,
+    ) -> Result<()> {
+        let required: u64 = order.maker_amount.parse().map_err(|_| {
+            Error::InvalidOrder(format!("Invalid maker amount: {}", order.maker_amount))
+        })?;
+        let (params, asset) = match order.side.as_str() {
+            "BUY" => (
+                BalanceAllowanceParams::new().asset_type(AssetType::Collateral),
+                "USDC".to_string(),
+            ),
+            _ => (
+                BalanceAllowanceParams::new()
+                    .asset_type(AssetType::Conditional)
+                    .token_id(order.token_id.clone()),
+                format!("token {}", order.token_id),
+            ),
+        };
+        let params = params.signature_type(order.signature_type);
+
+        let balance = self.get_balance_allowance(&params).await?;
+        let decimals = self.order_builder.collateral_decimals();
+        let required = token_u64_to_decimal(required, decimals);
+        // The balance is parsed with USDC's decimals; re-read it with the
+        // collateral's
+        let available = token_u64_to_decimal(
+            decimal_to_token_u64(balance.balance, USDC_DECIMALS)?,
+            decimals,
+        );
+        if available < required {
+            return Err(Error::InvalidOrder(format!(
+                "Insufficient {} balance: order needs {}, available {} (short {})",
+                asset,
+                required,
+                available,
+                required - available
+            )));
+        }
+
+        let exchange_address = exchange.exchange_address(self.chain_id)?;
+        let allowance = balance.allowances.iter().find_map(|(spender, allowance)| {
+            (spender.parse::<Address>().ok()? == exchange_address)
+                .then(|| U256::from_str_radix(allowance, 10).ok())
+                .flatten()
+        });
+        if let Some(allowance) = allowance {
+            let allowance =
+                token_u64_to_decimal(allowance.try_into().unwrap_or(u64::MAX), decimals);
+            if allowance < required {
+                return Err(Error::InvalidOrder(format!(
+                    "Insufficient {} allowance for the exchange: order needs {}, approved {} (short {})",
+                    asset,
+                    required,
+                    allowance,
+                    required - allowance
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetch the balance and allowances of one asset
+    async fn get_balance_allowance(
+        &self,
+        params: &BalanceAllowanceParams,
+    ) -> Result<BalanceAllowance> {
+        balance_allowance_request(
+            &self.http_client,
+            &self.signer,
+            &self.api_creds,
+            "/balance-allowance",
+            params,
+        )
+        .await
+    }
+
+    /// Check that a token's tick size is still `expected`
+    ///
+    /// A cheap pre-flight before posting orders built with a tick size the
+    /// caller assumed, since markets change tick size over time (see the
+    /// `tick_size_change` WebSocket event) and orders on a stale tick size
+    /// are rejected. The tick size is looked up with
+    /// [`ClobClient::get_tick_size`], so it comes from `clob`'s cache if
+    /// [caching](ClobClient::with_cache) is enabled; clear the cache when a
+    /// tick size change arrives.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] naming both tick sizes if they differ.
+    pub async fn assert_tick_size(
+        &self,
+        clob: &ClobClient,
+        token_id: &TokenId,
+        expected: Decimal,
+    ) -> Result<()> {
+        let tick_size = clob.get_tick_size(token_id).await?.minimum_tick_size;
+        if tick_size != expected {
+            return Err(Error::InvalidOrder(format!(
+                "Tick size of token {} is {}, expected {}",
+                token_id, tick_size, expected
+            )));
+        }
+        Ok(())
+    }
+
     /// Get open orders (L2 authentication required)
     ///
     /// # Arguments
@@ -213,6 +647,25 @@ impl TradingClient {
         self.http_client.get(&request_path, Some(headers)).await
     }
 
+    /// Get all open orders matching `params`, following pagination
+    ///
+    /// Requests pages until the server returns the end cursor, signing each
+    /// page request against the base path. Any `next_cursor` in `params` is
+    /// used as the starting point.
+    pub async fn all_open_orders(&self, params: OpenOrderParams) -> Result<Vec<OpenOrder>> {
+        let mut params = params;
+        let mut orders = Vec::new();
+        loop {
+            let page = self.get_orders(params.clone()).await?;
+            let next = page.next_cursor();
+            orders.extend(page.data);
+            match next {
+                Some(cursor) => params.next_cursor = Some(cursor.into()),
+                None => return Ok(orders),
+            }
+        }
+    }
+
     /// Get a specific order by ID
     pub async fn get_order(&self, order_id: &OrderId) -> Result<OpenOrder> {
         let path = format!("/data/order/{}", order_id.as_str());
@@ -241,9 +694,59 @@ impl TradingClient {
 
     /// Cancel multiple orders
     ///
+    /// Large lists are split into batches of
+    /// [`with_cancel_batch_size`](Self::with_cancel_batch_size) IDs (100 by
+    /// default) and cancelled with one request per batch, sequentially. The
+    /// results are combined into a single response.
+    ///
+    /// If the first batch fails, nothing was canceled and the error is
+    /// returned, as it is for a single batch. Once a batch has gone through,
+    /// a later batch whose request fails doesn't stop the others: its IDs are
+    /// reported in `not_canceled` with the error message, alongside the
+    /// results of the batches that went through. Authentication, signing and
+    /// transport errors are always returned as `Err` right away, since the
+    /// remaining batches would fail the same way.
+    ///
     /// # Arguments
     /// * `order_ids` - List of order IDs to cancel
     pub async fn cancel_orders(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
+        let mut combined = CancelOrdersResponse {
+            canceled: Vec::new(),
+            not_canceled: serde_json::Value::Object(Default::default()),
+        };
+        let mut any_succeeded = false;
+        for batch in order_ids.chunks(self.cancel_batch_size) {
+            match self.cancel_order_batch(batch).await {
+                Ok(response) => {
+                    any_succeeded = true;
+                    combined.merge(response)
+                }
+                // Nothing canceled yet, so report the failure as is
+                Err(e) if !any_succeeded => return Err(e),
+                // The remaining batches would fail the same way
+                Err(
+                    e @ (Error::Signing(_)
+                    | Error::AuthRequired(_)
+                    | Error::Http(_)
+                    | Error::Api {
+                        status: 401 | 403, ..
+                    }),
+                ) => return Err(e),
+                Err(e) => combined.merge(CancelOrdersResponse {
+                    canceled: Vec::new(),
+                    not_canceled: batch
+                        .iter()
+                        .map(|id| (id.to_string(), e.to_string().into()))
+                        .collect::<serde_json::Map<_, _>>()
+                        .into(),
+                }),
+            }
+        }
+        Ok(combined)
+    }
+
+    /// Cancel a list of orders with a single request
+    async fn cancel_order_batch(&self, order_ids: &[OrderId]) -> Result<CancelOrdersResponse> {
         let ids: Vec<&str> = order_ids.iter().map(|id| id.as_str()).collect();
         let body = serde_json::json!(ids);
         let headers = create_l2_headers(
@@ -273,6 +776,26 @@ impl TradingClient {
             .await
     }
 
+    /// Kill switch: cancel all orders on the CLOB and prepare an on-chain nonce bump
+    ///
+    /// Cancels every open order through [`cancel_all`](Self::cancel_all), which
+    /// takes effect immediately, then returns the exchange `incrementNonce()`
+    /// call. Sending that transaction from the order maker additionally
+    /// invalidates every order signed with the current nonce, including any
+    /// the CLOB still holds or that were handed out elsewhere. See
+    /// [`increment_nonce_call`] for who must send it.
+    ///
+    /// # Arguments
+    /// * `neg_risk` - Whether to bump the nonce on the neg risk exchange
+    pub async fn cancel_all_with_nonce(
+        &self,
+        neg_risk: bool,
+    ) -> Result<(CancelOrdersResponse, ExchangeCall)> {
+        let call = increment_nonce_call(self.chain_id, neg_risk)?;
+        let cancelled = self.cancel_all().await?;
+        Ok((cancelled, call))
+    }
+
     /// Cancel all orders for a specific market and/or asset
     ///
     /// # Arguments
@@ -301,11 +824,89 @@ impl TradingClient {
             .await
     }
 
+    /// Cancel all orders for a specific asset (token)
+    ///
+    /// Typed convenience wrapper over [`cancel_market_orders`](Self::cancel_market_orders).
+    ///
+    /// # Arguments
+    /// * `asset_id` - The token ID to cancel orders for
+    pub async fn cancel_by_asset(&self, asset_id: &TokenId) -> Result<CancelOrdersResponse> {
+        self.cancel_market_orders(None, Some(asset_id.as_str()))
+            .await
+    }
+
     /// Get trade history (L2 authentication required)
     ///
     /// # Arguments
     /// * `params` - Query parameters to filter trades
     pub async fn get_trades(&self, params: TradeParams) -> Result<serde_json::Value> {
+        self.fetch_trades(&params).await
+    }
+
+    /// Get one page of typed trade history (L2 authentication required)
+    ///
+    /// Pass the returned `next_cursor` back via [`TradeParams::next_cursor`]
+    /// to fetch the following page.
+    pub async fn get_trades_page(&self, params: TradeParams) -> Result<ClobTradesResponse> {
+        self.fetch_trades(&params).await
+    }
+
+    /// Stream trade history, following pagination
+    ///
+    /// Pages are fetched lazily as the stream is polled, so only one page is
+    /// held in memory at a time. The stream ends after the page carrying the
+    /// end cursor, and yields the first error it hits before ending.
+    pub fn trades_stream(
+        &self,
+        params: TradeParams,
+    ) -> impl Stream<Item = Result<ClobTrade>> + Send + '_ {
+        stream::try_unfold(Some(params), move |params| async move {
+            let Some(mut params) = params else {
+                return Result::Ok(None);
+            };
+            let page = self.get_trades_page(params.clone()).await?;
+            let next = page.next_cursor().map(|cursor| {
+                params.next_cursor = Some(cursor.into());
+                params
+            });
+            Ok(Some((page.data, next)))
+        })
+        .map_ok(|trades| stream::iter(trades.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Export trade history to `writer` as CSV or JSONL
+    ///
+    /// Trades are written as they arrive from [`trades_stream`](Self::trades_stream)
+    /// rather than collected first. Each record carries the fields listed in
+    /// [`ClobTrade::EXPORT_COLUMNS`]; CSV output starts with a header row.
+    /// `timestamp` is the match time in unix seconds.
+    ///
+    /// Returns the number of trades written.
+    pub async fn export_trades<W: Write>(
+        &self,
+        params: TradeParams,
+        mut writer: W,
+        format: ExportFormat,
+    ) -> Result<usize> {
+        if format == ExportFormat::Csv {
+            writeln!(writer, "{}", ClobTrade::EXPORT_COLUMNS.join(","))?;
+        }
+
+        let mut trades = std::pin::pin!(self.trades_stream(params));
+        let mut written = 0;
+        while let Some(trade) = trades.next().await {
+            trade?.write_export(&mut writer, format)?;
+            written += 1;
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
+    async fn fetch_trades<T: serde::de::DeserializeOwned>(
+        &self,
+        params: &TradeParams,
+    ) -> Result<T> {
         // IMPORTANT: Sign the base path WITHOUT query parameters
         let base_path = "/data/trades";
         let headers =
@@ -359,3 +960,563 @@ impl TradingClient {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::END_CURSOR;
+    use crate::transport::{HttpRequest, HttpResponse, MockTransport, Transport};
+    use alloy_signer_local::PrivateKeySigner;
+    use futures_util::future::BoxFuture;
+    use rust_decimal_macros::dec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn page(ids: &[&str], next_cursor: &str) -> String {
+        let orders: Vec<String> = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"{{"id": "{}", "associate_trades": [], "status": "LIVE", "market": "0x2",
+                        "original_size": "10", "outcome": "Yes", "maker_address": "0x3",
+                        "owner": "owner", "price": "0.5", "side": "BUY", "size_matched": "0",
+                        "asset_id": "1", "expiration": "0", "order_type": "GTC", "created_at": 1}}"#,
+                    id
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"limit": 2, "count": {}, "next_cursor": "{}", "data": [{}]}}"#,
+            ids.len(),
+            next_cursor,
+            orders.join(",")
+        )
+    }
+
+    fn client(mock: &MockTransport) -> TradingClient {
+        let signer = PrivateKeySigner::random();
+        let creds = ApiCreds::new("key".into(), "c2VjcmV0".into(), "pass".into());
+        let order_builder = OrderBuilder::new(signer.clone(), None, None);
+        TradingClient::new("https://clob.example", signer, 137, creds, order_builder)
+            .with_transport(mock.clone())
+    }
+
+    #[tokio::test]
+    async fn test_all_open_orders_follows_pagination() {
+        let mock = MockTransport::new()
+            .on("GET", "/data/orders", 200, page(&["0xa", "0xb"], "Mg=="))
+            .on(
+                "GET",
+                "/data/orders?asset_id=1&next_cursor=Mg==",
+                200,
+                page(&["0xc"], END_CURSOR),
+            );
+
+        let orders = client(&mock)
+            .all_open_orders(OpenOrderParams::new().asset_id("1"))
+            .await
+            .unwrap();
+        let ids: Vec<&str> = orders.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, ["0xa", "0xb", "0xc"]);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_balance_check_reports_shortfall() {
+        let balance = |balance: &str, allowance: &str| {
+            MockTransport::new().on(
+                "GET",
+                "/balance-allowance",
+                200,
+                format!(
+                    r#"{{"balance": "{}", "allowances": {{"0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E": "{}"}}}}"#,
+                    balance, allowance
+                ),
+            )
+        };
+        let args = OrderArgs::new("1234", dec!(0.5), dec!(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+
+        let mock = balance("2000000", "10000000");
+        let error = client(&mock)
+            .with_balance_check(true)
+            .create_and_post_order(&args, None, None, options.clone(), OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&error, Error::InvalidOrder(msg) if msg.contains("short 3")),
+            "{}",
+            error
+        );
+        assert!(mock.requests()[0].url.contains("asset_type=COLLATERAL"));
+
+        let mock = balance("10000000", "1000000");
+        let error = client(&mock)
+            .with_balance_check(true)
+            .create_and_post_order(&args, None, None, options, OrderType::Gtc)
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("allowance"), "{}", error);
+        // Nothing was posted
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_in_batches() {
+        let mock = MockTransport::new().on(
+            "DELETE",
+            "/orders",
+            200,
+            r#"{"canceled": ["0xa"], "not_canceled": {"0xb": "order matched"}}"#,
+        );
+        let ids: Vec<OrderId> = ["0xa", "0xb", "0xc"].map(OrderId::from).to_vec();
+
+        let response = client(&mock)
+            .with_cancel_batch_size(2)
+            .cancel_orders(&ids)
+            .await
+            .unwrap();
+
+        let bodies: Vec<String> = mock
+            .requests()
+            .into_iter()
+            .map(|r| r.body.unwrap())
+            .collect();
+        assert_eq!(bodies, [r#"["0xa","0xb"]"#, r#"["0xc"]"#]);
+        assert_eq!(response.canceled.len(), 2);
+        assert_eq!(response.not_canceled["0xb"], "order matched");
+    }
+
+    /// Transport answering the first request with `first` and failing every
+    /// later one with `status`
+    #[derive(Clone)]
+    struct FailAfterFirst {
+        first: String,
+        status: u16,
+        sent: Arc<AtomicUsize>,
+    }
+
+    impl FailAfterFirst {
+        fn new(first: &str, status: u16) -> Self {
+            Self {
+                first: first.to_string(),
+                status,
+                sent: Arc::default(),
+            }
+        }
+    }
+
+    impl Transport for FailAfterFirst {
+        fn send(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let sent = self.sent.fetch_add(1, Ordering::SeqCst);
+            let response = match sent {
+                0 => HttpResponse {
+                    status: 200,
+                    body: self.first.clone(),
+                },
+                _ => HttpResponse {
+                    status: self.status,
+                    body: "internal error".to_string(),
+                },
+            };
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_keeps_results_of_earlier_batches() {
+        let transport = FailAfterFirst::new(r#"{"canceled": ["0xa", "0xb"]}"#, 500);
+        let ids: Vec<OrderId> = ["0xa", "0xb", "0xc"].map(OrderId::from).to_vec();
+
+        let response = client(&MockTransport::new())
+            .with_transport(transport.clone())
+            .with_cancel_batch_size(2)
+            .cancel_orders(&ids)
+            .await
+            .unwrap();
+        assert_eq!(response.canceled, ["0xa", "0xb"].map(OrderId::from));
+        assert_eq!(response.count_not_canceled(), 1);
+        assert!(response.not_canceled["0xc"]
+            .as_str()
+            .unwrap()
+            .contains("internal error"));
+
+        // Auth failures are returned even after a batch went through
+        let result = client(&MockTransport::new())
+            .with_transport(FailAfterFirst::new(r#"{"canceled": ["0xa", "0xb"]}"#, 401))
+            .with_cancel_batch_size(2)
+            .cancel_orders(&ids)
+            .await;
+        assert!(matches!(result, Err(Error::Api { status: 401, .. })));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_returns_error_when_nothing_went_through() {
+        let mock = MockTransport::new().on("DELETE", "/orders", 500, "internal error");
+        let ids: Vec<OrderId> = ["0xa", "0xb", "0xc"].map(OrderId::from).to_vec();
+
+        // A single batch
+        let result = client(&mock).cancel_orders(&ids).await;
+        assert!(matches!(result, Err(Error::Api { status: 500, .. })));
+
+        // The first of several batches; the rest aren't sent
+        let result = client(&mock)
+            .with_cancel_batch_size(2)
+            .cancel_orders(&ids)
+            .await;
+        assert!(matches!(result, Err(Error::Api { status: 500, .. })));
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    fn trades_page(ids: &[&str], next_cursor: &str) -> String {
+        let trades: Vec<String> = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    r#"{{"id": "{}", "taker_order_id": "0xo", "market": "0x2", "asset_id": "1",
+                        "side": "SELL", "size": "10", "fee_rate_bps": "0", "price": "0.45",
+                        "status": "CONFIRMED", "match_time": "1700000000", "outcome": "Yes"}}"#,
+                    id
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"limit": 2, "count": {}, "next_cursor": "{}", "data": [{}]}}"#,
+            ids.len(),
+            next_cursor,
+            trades.join(",")
+        )
+    }
+
+    fn trades_mock() -> MockTransport {
+        MockTransport::new()
+            .on(
+                "GET",
+                "/data/trades",
+                200,
+                trades_page(&["t1", "t2"], "Mg=="),
+            )
+            .on(
+                "GET",
+                "/data/trades?next_cursor=Mg==",
+                200,
+                trades_page(&["t3"], END_CURSOR),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_trades_stream_follows_pagination() {
+        let mock = trades_mock();
+        let client = client(&mock);
+        let trades: Vec<ClobTrade> = client
+            .trades_stream(TradeParams::new())
+            .try_collect()
+            .await
+            .unwrap();
+        let ids: Vec<&str> = trades.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["t1", "t2", "t3"]);
+        assert_eq!(trades[0].match_time, 1700000000);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_trades_csv() {
+        let mock = trades_mock();
+        let mut out = Vec::new();
+        let written = client(&mock)
+            .export_trades(TradeParams::new(), &mut out, ExportFormat::Csv)
+            .await
+            .unwrap();
+
+        assert_eq!(written, 3);
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "timestamp,id,market,asset_id,side,price,size,fee_rate_bps,status"
+        );
+        assert_eq!(lines[1], "1700000000,t1,0x2,1,SELL,0.45,10,0,CONFIRMED");
+        assert_eq!(lines.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_export_trades_jsonl() {
+        let mock = trades_mock();
+        let mut out = Vec::new();
+        client(&mock)
+            .export_trades(TradeParams::new(), &mut out, ExportFormat::Jsonl)
+            .await
+            .unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let records: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[2]["id"], "t3");
+        assert_eq!(records[2]["price"], "0.45");
+        assert_eq!(records[2]["timestamp"], "1700000000");
+    }
+
+    #[tokio::test]
+    async fn test_assert_tick_size() {
+        let mock =
+            MockTransport::new().on("GET", "/tick-size", 200, r#"{"minimum_tick_size": 0.001}"#);
+        let client = client(&MockTransport::new());
+        let clob = ClobClient::new("https://clob.example").with_transport(mock.clone());
+        let token_id = TokenId::new("1");
+
+        client
+            .assert_tick_size(&clob, &token_id, dec!(0.0010))
+            .await
+            .unwrap();
+        let result = client.assert_tick_size(&clob, &token_id, dec!(0.01)).await;
+        assert!(matches!(
+            result,
+            Err(Error::InvalidOrder(msg)) if msg.contains("is 0.001, expected 0.01")
+        ));
+        assert!(mock.requests()[0].url.ends_with("/tick-size?token_id=1"));
+    }
+
+    #[tokio::test]
+    async fn test_assert_tick_size_uses_cache() {
+        let mock =
+            MockTransport::new().on("GET", "/tick-size", 200, r#"{"minimum_tick_size": 0.01}"#);
+        let client = client(&MockTransport::new());
+        let clob = ClobClient::new("https://clob.example")
+            .with_transport(mock.clone())
+            .with_cache(std::time::Duration::from_secs(60));
+        let token_id = TokenId::new("1");
+
+        for _ in 0..2 {
+            client
+                .assert_tick_size(&clob, &token_id, dec!(0.01))
+                .await
+                .unwrap();
+        }
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    fn thin_book() -> OrderBookSummary {
+        serde_json::from_str(
+            r#"{"market": "0x1", "asset_id": "1", "hash": "h", "timestamp": "1",
+                "bids": [], "asks": [{"price": "0.5", "size": "10"}, {"price": "0.6", "size": "5"}]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_market_order_insufficient_liquidity() {
+        let client = client(&MockTransport::new());
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+
+        let args = MarketOrderArgs::new("1", dec!(20), Side::Buy);
+        let result = client.create_market_order(&args, &thin_book(), None, options.clone());
+        match result {
+            Err(crate::Error::InsufficientLiquidity {
+                requested,
+                available,
+            }) => {
+                // 20 USDC requested, 10 * 0.5 + 5 * 0.6 USDC on the book
+                assert_eq!(requested, dec!(20));
+                assert_eq!(available, dec!(8));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let partial = client
+            .create_market_order(&args.allow_partial(true), &thin_book(), None, options)
+            .unwrap();
+        // Sized down to 8 USDC (the whole book) instead of 20
+        assert_eq!(partial.maker_amount, "8000000");
+    }
+
+    #[tokio::test]
+    async fn test_create_order_requires_tick_size_and_neg_risk() {
+        let mock = MockTransport::new();
+        let client = client(&mock).with_balance_check(true);
+        let args = OrderArgs::new("1", dec!(0.5), dec!(10), Side::Buy);
+
+        fn missing<T: std::fmt::Debug>(result: Result<T>) -> String {
+            match result {
+                Err(Error::MissingField(field)) => field,
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+        let defaults = CreateOrderOptions::default();
+        let tick_only = CreateOrderOptions::new().tick_size(dec!(0.01));
+        assert_eq!(
+            missing(client.create_order(&args, None, None, defaults.clone())),
+            "tick_size"
+        );
+        assert_eq!(
+            missing(client.create_order(&args, None, None, tick_only.clone())),
+            "neg_risk"
+        );
+        // Checked before the book is walked, so not reported as a liquidity error
+        let market_args = MarketOrderArgs::new("1", dec!(20), Side::Buy);
+        assert_eq!(
+            missing(client.create_market_order(&market_args, &thin_book(), None, defaults)),
+            "tick_size"
+        );
+        // and before the balance is fetched
+        let result = client
+            .create_and_post_order(&args, None, None, tick_only, OrderType::Gtc)
+            .await;
+        assert_eq!(missing(result), "neg_risk");
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_post_order_with_idempotency_key_dedupes_retries() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/order",
+            200,
+            r#"{"errorMsg": "", "orderID": "0xabc", "status": "live", "success": true}"#,
+        );
+        let client = client(&mock);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let args = OrderArgs::new("1", dec!(0.5), dec!(10), Side::Buy);
+
+        for _ in 0..2 {
+            let order = client
+                .create_order(&args, None, None, options.clone())
+                .unwrap();
+            let response = client
+                .post_order_with_idempotency_key(order, OrderType::Gtc, "order-1")
+                .await
+                .unwrap();
+            assert_eq!(response.order_id.as_str(), "0xabc");
+        }
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0]
+            .headers
+            .iter()
+            .any(|(name, value)| name == IDEMPOTENCY_KEY_HEADER && value == "order-1"));
+
+        client.clear_idempotency_keys();
+        let order = client.create_order(&args, None, None, options).unwrap();
+        client
+            .post_order_with_idempotency_key(order, OrderType::Gtc, "order-1")
+            .await
+            .unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_submission_body_matches_post_order() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/order",
+            200,
+            r#"{"errorMsg": "", "orderID": "0xabc", "status": "live", "success": true}"#,
+        );
+        let client = client(&mock);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let args = OrderArgs::new("1", dec!(0.5), dec!(10), Side::Buy);
+        let order = client.create_order(&args, None, None, options).unwrap();
+
+        let body = order.to_submission_body("key", OrderType::Gtd).unwrap();
+        let json = order.to_submission_json("key", OrderType::Gtd).unwrap();
+        client.post_order(order, OrderType::Gtd).await.unwrap();
+
+        let sent = mock.requests()[0].body.clone().unwrap();
+        assert_eq!(sent, body);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sent).unwrap(),
+            json
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_order_with_owner() {
+        let mock = MockTransport::new().on(
+            "POST",
+            "/order",
+            200,
+            r#"{"errorMsg": "", "orderID": "0xabc", "status": "live", "success": true}"#,
+        );
+        let client = client(&mock);
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(false);
+        let args = OrderArgs::new("1", dec!(0.5), dec!(10), Side::Buy);
+
+        let order = client.create_order(&args, None, None, options).unwrap();
+        client
+            .post_order(order.clone(), OrderType::Gtc)
+            .await
+            .unwrap();
+        client
+            .post_order_with_owner(order, OrderType::Gtc, "sub-account-key")
+            .await
+            .unwrap();
+
+        let owners: Vec<serde_json::Value> = mock
+            .requests()
+            .iter()
+            .map(|r| serde_json::from_str::<serde_json::Value>(r.body.as_ref().unwrap()).unwrap())
+            .map(|body| body["owner"].clone())
+            .collect();
+        assert_eq!(owners, ["key", "sub-account-key"]);
+    }
+
+    #[tokio::test]
+    async fn test_signature_diagnostics() {
+        let mock =
+            MockTransport::new().on("POST", "/order", 400, r#"{"error": "invalid signature"}"#);
+        let client = client(&mock);
+        // Signed for the neg risk exchange
+        let options = CreateOrderOptions::new()
+            .tick_size(dec!(0.01))
+            .neg_risk(true);
+        let args = OrderArgs::new("1", dec!(0.5), dec!(10), Side::Buy);
+        let order = client.create_order(&args, None, None, options).unwrap();
+
+        let result = client.post_order(order.clone(), OrderType::Gtc).await;
+        assert!(matches!(result, Err(Error::Api { status: 400, .. })));
+
+        let client = client.with_signature_diagnostics(true);
+        match client.post_order(order.clone(), OrderType::Gtc).await {
+            Err(Error::Signing(message)) => {
+                assert!(message.contains("invalid signature"));
+                let hash = order_request_hash(
+                    &order,
+                    137,
+                    ExchangeVariant::NegRisk.exchange_address(137).unwrap(),
+                )
+                .unwrap();
+                assert!(message.contains(&hash.to_string()));
+                assert!(message.contains("valid for the NegRisk exchange on chain 137"));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        // Idempotent posts go through the same checks
+        let result = client
+            .post_order_with_idempotency_key(order, OrderType::Gtc, "key-1")
+            .await;
+        assert!(matches!(result, Err(Error::Signing(_))));
+        let requests = mock.requests();
+        assert!(requests
+            .last()
+            .unwrap()
+            .headers
+            .iter()
+            .any(
+                |(name, value)| name.eq_ignore_ascii_case(IDEMPOTENCY_KEY_HEADER)
+                    && value == "key-1"
+            ));
+    }
+}
@@ -0,0 +1,19 @@
+//! Base URLs of the Polymarket APIs
+//!
+//! Each client talks to its own API, and requests sent to the wrong host
+//! fail with 404s:
+//!
+//! | Client | Host |
+//! |--------|------|
+//! | [`ClobClient`](crate::ClobClient), [`AuthenticatedClient`](crate::AuthenticatedClient), [`TradingClient`](crate::TradingClient) | [`CLOB`] |
+//! | [`DataClient`](crate::DataClient) | [`DATA_API`] |
+//! | [`GammaClient`](crate::GammaClient) | [`GAMMA_API`] |
+
+/// CLOB API: order books, prices, orders and the authenticated user's trades
+pub const CLOB: &str = "https://clob.polymarket.com";
+
+/// Data API: positions, portfolio value, activity and public trade history
+pub const DATA_API: &str = "https://data-api.polymarket.com";
+
+/// Gamma API: market and event discovery and metadata
+pub const GAMMA_API: &str = "https://gamma-api.polymarket.com";
@@ -1,3 +1,4 @@
 mod contracts;
+pub mod hosts;
 
 pub use contracts::{chains, get_contract_config, ContractConfig};
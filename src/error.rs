@@ -1,3 +1,4 @@
+use crate::websocket::CloseReason;
 use std::fmt;
 
 /// Result type for polymarket-rs operations
@@ -30,9 +31,31 @@ pub enum Error {
     /// Decimal conversion error
     Decimal(rust_decimal::Error),
 
+    /// I/O error (e.g. while writing an export)
+    Io(std::io::Error),
+
     /// Invalid order configuration
     InvalidOrder(String),
 
+    /// Not enough liquidity in the order book to fill a market order
+    ///
+    /// Both amounts are in the unit of the requested amount: USDC for market
+    /// buys and shares for market sells from
+    /// [`TradingClient::create_market_order`](crate::TradingClient::create_market_order),
+    /// shares from [`calculate_market_price`](crate::orders::calculate_market_price).
+    InsufficientLiquidity {
+        requested: rust_decimal::Decimal,
+        available: rust_decimal::Decimal,
+    },
+
+    /// The market is closed, resolved, or not accepting orders
+    ///
+    /// Retrying won't help until the market reopens.
+    MarketClosed {
+        token_id: Option<String>,
+        message: String,
+    },
+
     /// Missing required field
     MissingField(String),
 
@@ -42,6 +65,17 @@ pub enum Error {
     /// WebSocket connection closed
     ConnectionClosed,
 
+    /// The server closed the WebSocket connection with a Close frame
+    ///
+    /// Check [`CloseReason::is_retryable`] before reconnecting.
+    ServerClosed {
+        reason: CloseReason,
+        message: String,
+    },
+
+    /// Operation timed out
+    Timeout(String),
+
     /// Reconnection failed after multiple attempts
     ReconnectFailed {
         attempts: u32,
@@ -62,10 +96,31 @@ impl fmt::Display for Error {
                 write!(f, "API error (status {}): {}", status, message)
             }
             Error::Decimal(e) => write!(f, "Decimal error: {}", e),
+            Error::Io(e) => write!(f, "I/O error: {}", e),
             Error::InvalidOrder(msg) => write!(f, "Invalid order: {}", msg),
+            Error::InsufficientLiquidity {
+                requested,
+                available,
+            } => write!(
+                f,
+                "Insufficient liquidity: requested {}, available {}",
+                requested, available
+            ),
+            Error::MarketClosed { token_id, message } => match token_id {
+                Some(token_id) => write!(f, "Market closed for token {}: {}", token_id, message),
+                None => write!(f, "Market closed: {}", message),
+            },
             Error::MissingField(field) => write!(f, "Missing required field: {}", field),
             Error::WebSocket(msg) => write!(f, "WebSocket error: {}", msg),
             Error::ConnectionClosed => write!(f, "WebSocket connection closed"),
+            Error::ServerClosed { reason, message } => write!(
+                f,
+                "WebSocket closed by server ({}, code {}): {}",
+                reason,
+                reason.code(),
+                message
+            ),
+            Error::Timeout(msg) => write!(f, "Timeout: {}", msg),
             Error::ReconnectFailed {
                 attempts,
                 last_error,
@@ -78,12 +133,64 @@ impl fmt::Display for Error {
     }
 }
 
+impl Error {
+    /// Short, stable name of the error variant, e.g. for metrics labels
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Http(_) => "http",
+            Error::Json(_) => "json",
+            Error::Config(_) => "config",
+            Error::AuthRequired(_) => "auth_required",
+            Error::Signing(_) => "signing",
+            Error::InvalidParameter(_) => "invalid_parameter",
+            Error::Api { .. } => "api",
+            Error::Decimal(_) => "decimal",
+            Error::Io(_) => "io",
+            Error::InvalidOrder(_) => "invalid_order",
+            Error::InsufficientLiquidity { .. } => "insufficient_liquidity",
+            Error::MarketClosed { .. } => "market_closed",
+            Error::MissingField(_) => "missing_field",
+            Error::WebSocket(_) => "websocket",
+            Error::ConnectionClosed => "connection_closed",
+            Error::ServerClosed { .. } => "server_closed",
+            Error::Timeout(_) => "timeout",
+            Error::ReconnectFailed { .. } => "reconnect_failed",
+        }
+    }
+
+    /// Turn an API error saying the market is closed into [`Error::MarketClosed`]
+    ///
+    /// Only the server's messages for closed markets match; other errors,
+    /// including other 4xx errors such as unknown orders or tokens, are
+    /// returned unchanged.
+    pub(crate) fn into_market_closed(self, token_id: Option<&str>) -> Self {
+        const CLOSED_MARKERS: [&str; 3] =
+            ["not accepting orders", "market is closed", "market closed"];
+
+        match self {
+            Error::Api { status, message }
+                if (400..500).contains(&status)
+                    && CLOSED_MARKERS
+                        .iter()
+                        .any(|marker| message.to_ascii_lowercase().contains(marker)) =>
+            {
+                Error::MarketClosed {
+                    token_id: token_id.map(str::to_string),
+                    message,
+                }
+            }
+            other => other,
+        }
+    }
+}
+
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Error::Http(e) => Some(e),
             Error::Json(e) => Some(e),
             Error::Decimal(e) => Some(e),
+            Error::Io(e) => Some(e),
             _ => None,
         }
     }
@@ -101,6 +208,12 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
 impl From<rust_decimal::Error> for Error {
     fn from(err: rust_decimal::Error) -> Self {
         Error::Decimal(err)
@@ -118,3 +231,40 @@ impl From<tokio_tungstenite::tungstenite::Error> for Error {
         Error::WebSocket(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_market_closed() {
+        let error = Error::Api {
+            status: 400,
+            message: r#"{"error": "Market is closed, not accepting orders"}"#.to_string(),
+        };
+        match error.into_market_closed(Some("123")) {
+            Error::MarketClosed { token_id, .. } => assert_eq!(token_id.as_deref(), Some("123")),
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        for message in [
+            "invalid signature",
+            r#"{"error": "order 0x1 does not exist"}"#,
+            r#"{"error": "the orderbook 123 does not exist"}"#,
+            r#"{"error": "API key does not exist"}"#,
+        ] {
+            let error = Error::Api {
+                status: 400,
+                message: message.to_string(),
+            };
+            assert!(
+                matches!(
+                    error.into_market_closed(Some("123")),
+                    Error::Api { status: 400, .. }
+                ),
+                "{}",
+                message
+            );
+        }
+    }
+}
@@ -1,40 +1,102 @@
 use crate::error::{Error, Result};
-use reqwest::{Client, Response};
+use crate::interceptor::{HttpInterceptor, RequestInfo, ResponseInfo};
+use crate::transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport};
+use reqwest::header::HeaderMap;
+use reqwest::Method;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Semaphore;
 
 /// HTTP client wrapper for making API requests
 #[derive(Clone)]
 pub struct HttpClient {
-    client: Client,
+    transport: Arc<dyn Transport>,
     base_url: String,
+    path_prefix: String,
+    default_headers: HeaderMap,
+    limiter: Option<Arc<Semaphore>>,
+    interceptor: Option<Arc<dyn HttpInterceptor>>,
 }
 
 impl HttpClient {
     pub fn new(base_url: impl Into<String>) -> Self {
         Self {
-            client: Client::new(),
+            transport: Arc::new(ReqwestTransport::new()),
             base_url: base_url.into(),
+            path_prefix: String::new(),
+            default_headers: HeaderMap::new(),
+            limiter: None,
+            interceptor: None,
         }
     }
 
+    /// Replace the transport used to send requests
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Prefix every request path, e.g. `/clob` for a gateway serving `/clob/order`
+    ///
+    /// The prefix only affects the request URL. L2 signatures keep covering the
+    /// unprefixed path, which is what the exchange sees once the gateway strips
+    /// the prefix, so signed requests stay valid behind the proxy.
+    pub fn with_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        let prefix = prefix.into();
+        self.path_prefix = format!("/{}", prefix.trim_matches('/'));
+        if self.path_prefix == "/" {
+            self.path_prefix.clear();
+        }
+        self
+    }
+
+    /// Client for another host sharing this client's transport, default headers,
+    /// concurrency limit and interceptor
+    ///
+    /// The path prefix is not carried over.
+    pub fn for_base_url(&self, base_url: impl Into<String>) -> Self {
+        Self {
+            transport: Arc::clone(&self.transport),
+            base_url: base_url.into(),
+            path_prefix: String::new(),
+            default_headers: self.default_headers.clone(),
+            limiter: self.limiter.clone(),
+            interceptor: self.interceptor.clone(),
+        }
+    }
+
+    /// Allow at most `max` requests in flight at once; further requests wait
+    ///
+    /// Clones of the client share the limit. A `max` of 0 is treated as 1.
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.limiter = Some(Arc::new(Semaphore::new(max.max(1))));
+        self
+    }
+
+    /// Set headers sent with every request (e.g. `User-Agent`, `X-Request-Id`)
+    ///
+    /// Per-request headers such as the L1/L2 auth headers are merged in on top
+    /// and win when a name appears in both.
+    pub fn with_default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Observe every request and response with `interceptor`
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn HttpInterceptor>) -> Self {
+        self.interceptor = Some(interceptor);
+        self
+    }
+
     /// Make a GET request
     pub async fn get<T>(&self, path: &str, headers: Option<HashMap<&str, String>>) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.get(&url);
-
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send::<T, ()>(Method::GET, path, None, headers).await
     }
 
     /// Make a POST request with JSON body
@@ -48,17 +110,37 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.post(&url).json(body);
+        self.send(Method::POST, path, Some(body), headers).await
+    }
 
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
+    /// Make a PUT request with JSON body
+    #[allow(dead_code)] // no built-in endpoint uses PUT yet
+    pub async fn put<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send(Method::PUT, path, Some(body), headers).await
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Make a PATCH request with JSON body
+    #[allow(dead_code)] // no built-in endpoint uses PATCH yet
+    pub async fn patch<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send(Method::PATCH, path, Some(body), headers).await
     }
 
     /// Make a DELETE request with optional JSON body
@@ -66,17 +148,8 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.delete(&url);
-
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
-        }
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        self.send::<T, ()>(Method::DELETE, path, None, headers)
+            .await
     }
 
     /// Make a DELETE request with JSON body
@@ -90,38 +163,368 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let mut request = self.client.delete(&url).json(body);
+        self.send(Method::DELETE, path, Some(body), headers).await
+    }
+
+    /// Make a request with an arbitrary HTTP method and optional JSON body
+    pub async fn request<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        self.send(method, path, body, headers).await
+    }
+
+    /// Build the request, send it through the transport and handle the response
+    async fn send<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        headers: Option<HashMap<&str, String>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        #[cfg(feature = "metrics")]
+        let (metrics_method, metrics_path) = (
+            method.clone(),
+            path.split('?').next().unwrap_or_default().to_string(),
+        );
+        #[cfg(feature = "metrics")]
+        crate::metrics::record(|m| m.request_sent(metrics_method.as_str(), &metrics_path));
+
+        let request = HttpRequest {
+            method,
+            url: format!("{}{}{}", self.base_url, self.path_prefix, path),
+            headers: self.merge_headers(headers.unwrap_or_default()),
+            body: body.map(serde_json::to_string).transpose()?,
+        };
+
+        // Held until the response is received; the semaphore is never closed
+        let _permit = match &self.limiter {
+            Some(limiter) => limiter.acquire().await.ok(),
+            None => None,
+        };
+
+        let result = match self.dispatch(request).await {
+            Ok(response) => self.handle_response(response.status, response.body),
+            Err(e) => Err(e),
+        };
+
+        #[cfg(feature = "metrics")]
+        if let Err(e) = &result {
+            crate::metrics::record(|m| m.request_failed(metrics_method.as_str(), &metrics_path, e));
+        }
+
+        result
+    }
+
+    /// Send `request` through the transport, reporting it to the interceptor
+    async fn dispatch(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let Some(interceptor) = &self.interceptor else {
+            return self.transport.send(request).await;
+        };
 
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
+        // The transport takes the request by value, so keep a copy to report on
+        let sent = request.clone();
+        let info = RequestInfo {
+            method: &sent.method,
+            url: &sent.url,
+            headers: &sent.headers,
+            body: sent.body.as_deref(),
+        };
+        interceptor.on_request(&info);
+
+        let started = Instant::now();
+        match self.transport.send(request).await {
+            Ok(response) => {
+                interceptor.on_response(&ResponseInfo {
+                    method: &sent.method,
+                    url: &sent.url,
+                    status: response.status,
+                    body: &response.body,
+                    elapsed: started.elapsed(),
+                });
+                Ok(response)
+            }
+            Err(e) => {
+                interceptor.on_error(&info, &e);
+                Err(e)
             }
         }
+    }
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+    /// Combine default headers with per-request headers, the latter taking precedence
+    fn merge_headers(&self, headers: HashMap<&str, String>) -> Vec<(String, String)> {
+        let mut merged: Vec<(String, String)> = self
+            .default_headers
+            .iter()
+            .filter(|(name, _)| {
+                !headers
+                    .keys()
+                    .any(|key| key.eq_ignore_ascii_case(name.as_str()))
+            })
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        merged.extend(
+            headers
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value)),
+        );
+        merged
     }
 
     /// Handle response and parse JSON or return error
-    async fn handle_response<T>(&self, response: Response) -> Result<T>
+    ///
+    /// HTML bodies, typically error pages from the Cloudflare gateway in front
+    /// of the API, become [`Error::Api`] with a short excerpt instead of a
+    /// JSON error or the whole page, even when the status is 2xx.
+    fn handle_response<T>(&self, status: u16, body: String) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let status = response.status();
+        if looks_like_html(&body) {
+            return Err(Error::Api {
+                status,
+                message: format!(
+                    "Server returned HTML instead of JSON (likely a gateway error): {}",
+                    html_excerpt(&body)
+                ),
+            });
+        }
 
-        if status.is_success() {
-            response.json().await.map_err(|e| e.into())
+        if (200..300).contains(&status) {
+            serde_json::from_str(&body).map_err(|e| e.into())
         } else {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            Err(Error::Api {
-                status: status.as_u16(),
-                message,
-            })
+            let message = if body.is_empty() {
+                "Unknown error".to_string()
+            } else {
+                body
+            };
+
+            Err(Error::Api { status, message })
+        }
+    }
+}
+
+/// Maximum length of the HTML excerpt included in error messages
+const HTML_EXCERPT_LEN: usize = 200;
+
+/// Whether a response body is an HTML page rather than JSON
+///
+/// Transports don't expose response headers, so this sniffs the body: JSON
+/// never starts with `<`.
+fn looks_like_html(body: &str) -> bool {
+    body.trim_start().starts_with('<')
+}
+
+/// The page `<title>` if there is one, else the start of the body
+fn html_excerpt(body: &str) -> String {
+    let lower = body.to_ascii_lowercase();
+    let title = lower.find("<title>").and_then(|start| {
+        let start = start + "<title>".len();
+        let end = start + lower[start..].find("</title>")?;
+        Some(&body[start..end])
+    });
+    let text = title.unwrap_or(body);
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() > HTML_EXCERPT_LEN {
+        let truncated: String = text.chars().take(HTML_EXCERPT_LEN).collect();
+        format!("{}...", truncated)
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use reqwest::header::{HeaderValue, USER_AGENT};
+
+    #[tokio::test]
+    async fn test_default_headers_do_not_override_request_headers() {
+        let mock = MockTransport::new().on("GET", "/orders", 200, "[]");
+        let mut defaults = HeaderMap::new();
+        defaults.insert(USER_AGENT, HeaderValue::from_static("my-bot/1.0"));
+        defaults.insert("poly_api_key", HeaderValue::from_static("default-key"));
+        let client = HttpClient::new("https://example.com")
+            .with_transport(mock.clone())
+            .with_default_headers(defaults);
+
+        let headers = HashMap::from([("POLY_API_KEY", "real-key".to_string())]);
+        let _: Vec<serde_json::Value> = client.get("/orders", Some(headers)).await.unwrap();
+
+        let sent = &mock.requests()[0].headers;
+        assert!(sent.contains(&("user-agent".to_string(), "my-bot/1.0".to_string())));
+        assert!(sent.contains(&("POLY_API_KEY".to_string(), "real-key".to_string())));
+        assert!(!sent.iter().any(|(_, value)| value == "default-key"));
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_sees_request_and_response() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<String>>);
+
+        impl HttpInterceptor for Recorder {
+            fn on_request(&self, request: &RequestInfo<'_>) {
+                self.0.lock().unwrap().push(format!(
+                    "{} {} {:?} {:?}",
+                    request.method,
+                    request.url,
+                    request.redacted_headers(),
+                    request.body
+                ));
+            }
+
+            fn on_response(&self, response: &ResponseInfo<'_>) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", response.status, response.body));
+            }
+        }
+
+        let mock = MockTransport::new().on("POST", "/order", 400, r#"{"error": "bad"}"#);
+        let recorder = Arc::new(Recorder::default());
+        let client = HttpClient::new("https://clob.example")
+            .with_transport(mock)
+            .with_interceptor(recorder.clone());
+
+        let headers = HashMap::from([("POLY_PASSPHRASE", "secret".to_string())]);
+        let result: Result<serde_json::Value> = client
+            .post("/order", &serde_json::json!({"a": 1}), Some(headers))
+            .await;
+        assert!(result.is_err());
+
+        let log = recorder.0.lock().unwrap();
+        assert_eq!(
+            *log,
+            [
+                r#"POST https://clob.example/order [("POLY_PASSPHRASE", "<redacted>")] Some("{\"a\":1}")"#,
+                r#"400 {"error": "bad"}"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_html_error_page() {
+        let page = r#"<!DOCTYPE html>
+            <html><head><title>Just a moment... | Cloudflare</title></head>
+            <body>Checking your browser</body></html>"#;
+        let mock = MockTransport::new()
+            .on("GET", "/book", 502, page)
+            .on("GET", "/price", 200, page)
+            .on("GET", "/midpoint", 200, "{not json");
+        let client = HttpClient::new("https://example.com").with_transport(mock);
+
+        for path in ["/book", "/price"] {
+            match client.get::<serde_json::Value>(path, None).await {
+                Err(Error::Api { message, .. }) => {
+                    assert!(message.starts_with("Server returned HTML"), "{}", message);
+                    assert!(
+                        message.ends_with("Just a moment... | Cloudflare"),
+                        "{}",
+                        message
+                    );
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+        // Malformed JSON is still a JSON error
+        assert!(matches!(
+            client.get::<serde_json::Value>("/midpoint", None).await,
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_html_excerpt_is_truncated() {
+        let body = format!("<html><body>{}</body></html>", "x".repeat(500));
+        let excerpt = html_excerpt(&body);
+        assert_eq!(excerpt.chars().count(), HTML_EXCERPT_LEN + 3);
+        assert!(excerpt.starts_with("<html><body>xxx"));
+    }
+
+    #[tokio::test]
+    async fn test_put_and_patch() {
+        let mock = MockTransport::new()
+            .on("PUT", "/thing", 200, "{}")
+            .on("PATCH", "/thing", 200, "{}");
+        let client = HttpClient::new("https://example.com").with_transport(mock.clone());
+
+        let body = serde_json::json!({"a": 1});
+        let _: serde_json::Value = client.put("/thing", &body, None).await.unwrap();
+        let _: serde_json::Value = client.patch("/thing", &body, None).await.unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].method, Method::PUT);
+        assert_eq!(requests[1].method, Method::PATCH);
+        assert_eq!(requests[1].body.as_deref(), Some(r#"{"a":1}"#));
+    }
+
+    #[tokio::test]
+    async fn test_path_prefix_applies_to_url() {
+        let mock = MockTransport::new().on("GET", "/clob/book", 200, "{}");
+        let client = HttpClient::new("https://gateway.example")
+            .with_transport(mock.clone())
+            .with_path_prefix("clob/");
+
+        let _: serde_json::Value = client.get("/book?token_id=1", None).await.unwrap();
+        assert_eq!(
+            mock.requests()[0].url,
+            "https://gateway.example/clob/book?token_id=1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency() {
+        use crate::transport::HttpResponse;
+        use futures_util::future::BoxFuture;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct SlowTransport {
+            in_flight: AtomicUsize,
+            max_in_flight: AtomicUsize,
+        }
+
+        impl Transport for SlowTransport {
+            fn send(&self, _request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+                Box::pin(async move {
+                    let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok(HttpResponse {
+                        status: 200,
+                        body: "{}".to_string(),
+                    })
+                })
+            }
         }
+
+        let transport = Arc::new(SlowTransport::default());
+        let mut client = HttpClient::new("https://clob.example").with_max_concurrency(2);
+        client.transport = transport.clone();
+
+        let requests = (0..8).map(|_| client.get::<serde_json::Value>("/book", None));
+        futures_util::future::try_join_all(requests).await.unwrap();
+        assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 2);
     }
 }
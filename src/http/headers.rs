@@ -1,7 +1,7 @@
 use crate::error::Result;
-use crate::signing::{sign_clob_auth_message, EthSigner};
+use crate::signing::{build_hmac_signature, sign_clob_auth_message, EthSigner};
 use crate::types::ApiCreds;
-use crate::utils::{build_hmac_signature, get_current_unix_time_secs};
+use crate::utils::get_current_unix_time_secs;
 use alloy_primitives::hex::encode_prefixed;
 use alloy_primitives::U256;
 use serde::Serialize;
@@ -42,6 +42,9 @@ pub fn create_l1_headers<S: EthSigner>(
 ///
 /// These headers are used for API operations that require API credentials,
 /// such as creating orders, querying private data, etc.
+///
+/// Any HTTP method is accepted. It is uppercased before signing, since the
+/// server signs the canonical method name (`GET`, `PUT`, `PATCH`, ...).
 pub fn create_l2_headers<S: EthSigner, T>(
     signer: &S,
     api_creds: &ApiCreds,
@@ -52,11 +55,26 @@ pub fn create_l2_headers<S: EthSigner, T>(
 where
     T: ?Sized + Serialize,
 {
-    let address = encode_prefixed(signer.address().as_slice());
     let timestamp = get_current_unix_time_secs()?;
+    create_l2_headers_at(signer, api_creds, method, req_path, body, timestamp)
+}
+
+fn create_l2_headers_at<S: EthSigner, T>(
+    signer: &S,
+    api_creds: &ApiCreds,
+    method: &str,
+    req_path: &str,
+    body: Option<&T>,
+    timestamp: u64,
+) -> Result<Headers>
+where
+    T: ?Sized + Serialize,
+{
+    let address = encode_prefixed(signer.address().as_slice());
+    let method = method.to_ascii_uppercase();
 
     let hmac_signature =
-        build_hmac_signature(&api_creds.secret, timestamp, method, req_path, body)?;
+        build_hmac_signature(&api_creds.secret, timestamp, &method, req_path, body)?;
 
     Ok(HashMap::from([
         (POLY_ADDR_HEADER, address),
@@ -77,4 +95,25 @@ mod tests {
         assert_eq!(POLY_SIG_HEADER, "POLY_SIGNATURE");
         assert_eq!(POLY_TS_HEADER, "POLY_TIMESTAMP");
     }
+
+    #[test]
+    fn test_l2_headers_normalize_method_case() {
+        let signer = alloy_signer_local::PrivateKeySigner::random();
+        let creds = ApiCreds::new(
+            "key".into(),
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".into(),
+            "pass".into(),
+        );
+        let body = serde_json::json!({"a": 1});
+
+        let lower =
+            create_l2_headers_at(&signer, &creds, "put", "/thing", Some(&body), 1000).unwrap();
+        let upper =
+            create_l2_headers_at(&signer, &creds, "PUT", "/thing", Some(&body), 1000).unwrap();
+        assert_eq!(lower[POLY_SIG_HEADER], upper[POLY_SIG_HEADER]);
+
+        let patch =
+            create_l2_headers_at(&signer, &creds, "PATCH", "/thing", Some(&body), 1000).unwrap();
+        assert_ne!(patch[POLY_SIG_HEADER], upper[POLY_SIG_HEADER]);
+    }
 }
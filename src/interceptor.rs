@@ -0,0 +1,140 @@
+//! Hooks for observing HTTP traffic
+//!
+//! Implement [`HttpInterceptor`] to see every request exactly as it is sent
+//! (method, URL, headers, JSON body) and every response as it is received,
+//! e.g. to debug signature mismatches or to capture fixtures for
+//! [`MockTransport`](crate::transport::MockTransport). Install it on a client
+//! with `with_interceptor`. Every method has an empty default, so only the
+//! callbacks of interest need to be implemented.
+//!
+//! Requests carry credentials in their headers. Check [`is_secret_header`]
+//! or use [`RequestInfo::redacted_headers`] before logging or storing them.
+//!
+//! ```
+//! use polymarket_rs::interceptor::{HttpInterceptor, RequestInfo, ResponseInfo};
+//! use polymarket_rs::ClobClient;
+//! use std::sync::Arc;
+//!
+//! struct Logger;
+//!
+//! impl HttpInterceptor for Logger {
+//!     fn on_request(&self, request: &RequestInfo<'_>) {
+//!         println!("{} {} {:?}", request.method, request.url, request.redacted_headers());
+//!     }
+//!
+//!     fn on_response(&self, response: &ResponseInfo<'_>) {
+//!         println!("{} {} -> {}", response.method, response.url, response.status);
+//!     }
+//! }
+//!
+//! let client = ClobClient::new("https://clob.polymarket.com").with_interceptor(Arc::new(Logger));
+//! ```
+
+use reqwest::Method;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// Value substituted for secret header values by [`RequestInfo::redacted_headers`]
+pub const REDACTED: &str = "<redacted>";
+
+/// Headers whose values are credentials: the API key and passphrase, and the
+/// L1/L2 signatures, which can be replayed until their timestamp expires
+const SECRET_HEADERS: &[&str] = &[
+    "POLY_API_KEY",
+    "POLY_PASSPHRASE",
+    "POLY_SIGNATURE",
+    "Authorization",
+    "Cookie",
+];
+
+/// Whether the header `name` carries a secret, compared case-insensitively
+pub fn is_secret_header(name: &str) -> bool {
+    SECRET_HEADERS
+        .iter()
+        .any(|secret| secret.eq_ignore_ascii_case(name))
+}
+
+/// Outgoing request, as handed to the transport
+#[derive(Debug, Clone, Copy)]
+pub struct RequestInfo<'a> {
+    pub method: &'a Method,
+    /// Full request URL, including query parameters
+    pub url: &'a str,
+    /// Request headers, including secrets; see [`is_secret_header`]
+    pub headers: &'a [(String, String)],
+    /// JSON-encoded request body
+    pub body: Option<&'a str>,
+}
+
+impl RequestInfo<'_> {
+    /// Headers with secret values replaced by [`REDACTED`]
+    pub fn redacted_headers(&self) -> Vec<(&str, &str)> {
+        self.headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if is_secret_header(name) {
+                    REDACTED
+                } else {
+                    value.as_str()
+                };
+                (name.as_str(), value)
+            })
+            .collect()
+    }
+}
+
+/// Response received for a request
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseInfo<'a> {
+    pub method: &'a Method,
+    pub url: &'a str,
+    /// HTTP status code
+    pub status: u16,
+    /// Raw response body
+    pub body: &'a str,
+    /// Time between handing the request to the transport and the response
+    pub elapsed: Duration,
+}
+
+/// Observer of the requests and responses of a client
+///
+/// Callbacks run inline on the request path and can't modify the traffic.
+pub trait HttpInterceptor: Send + Sync {
+    /// A request is about to be sent
+    fn on_request(&self, _request: &RequestInfo<'_>) {}
+
+    /// A response was received, whatever its status
+    fn on_response(&self, _response: &ResponseInfo<'_>) {}
+
+    /// The transport failed before a response was received
+    fn on_error(&self, _request: &RequestInfo<'_>, _error: &Error) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacted_headers() {
+        let headers = vec![
+            ("POLY_ADDRESS".to_string(), "0xabc".to_string()),
+            ("poly_passphrase".to_string(), "hunter2".to_string()),
+            ("POLY_SIGNATURE".to_string(), "sig".to_string()),
+        ];
+        let request = RequestInfo {
+            method: &Method::GET,
+            url: "https://clob.example/orders",
+            headers: &headers,
+            body: None,
+        };
+        assert_eq!(
+            request.redacted_headers(),
+            [
+                ("POLY_ADDRESS", "0xabc"),
+                ("poly_passphrase", REDACTED),
+                ("POLY_SIGNATURE", REDACTED),
+            ]
+        );
+    }
+}
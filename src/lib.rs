@@ -22,9 +22,13 @@
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod interceptor;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod orders;
 pub mod request;
 pub mod signing;
+pub mod transport;
 pub mod types;
 pub mod websocket;
 
@@ -51,8 +55,8 @@ pub use websocket::{MarketWsClient, UserWsClient};
 // Re-export order builder
 pub use orders::OrderBuilder;
 
-// Re-export signer trait
-pub use signing::EthSigner;
+// Re-export signer traits
+pub use signing::{AsyncEthSigner, EthSigner};
 
 // Re-export stream extension traits
 pub use futures_util::StreamExt;
@@ -0,0 +1,120 @@
+//! Client health metrics
+//!
+//! Implement [`Metrics`] to forward client events to your monitoring system
+//! (Prometheus, StatsD, the `metrics` crate, ...) and install it once at
+//! startup with [`set_metrics`]. Every method has an empty default, so only
+//! the events of interest need to be implemented.
+//!
+//! Recording is compiled in only with the `metrics` feature; without it this
+//! module doesn't exist and the clients carry no instrumentation at all.
+//!
+//! ```
+//! use polymarket_rs::metrics::{set_metrics, Metrics};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//!
+//! #[derive(Default)]
+//! struct Counters {
+//!     requests: AtomicU64,
+//! }
+//!
+//! impl Metrics for Counters {
+//!     fn request_sent(&self, _method: &str, _path: &str) {
+//!         self.requests.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! set_metrics(Counters::default()).unwrap();
+//! ```
+
+use crate::error::{Error, Result};
+use std::sync::OnceLock;
+
+/// Receiver of client events
+///
+/// Methods are called inline on the hot path and must be cheap; increment
+/// counters rather than doing I/O.
+pub trait Metrics: Send + Sync {
+    /// An HTTP request is about to be sent
+    ///
+    /// `path` excludes the query string to keep label cardinality low.
+    fn request_sent(&self, _method: &str, _path: &str) {}
+
+    /// An HTTP request failed, either in transport or with an error response
+    ///
+    /// Use [`Error::kind`] to group errors by type.
+    fn request_failed(&self, _method: &str, _path: &str, _error: &Error) {}
+
+    /// An operation is being retried, e.g. `"create_api_key"` with a bumped nonce
+    fn retry(&self, _operation: &'static str) {}
+
+    /// A [`ReconnectingStream`](crate::websocket::ReconnectingStream) is about
+    /// to reconnect; `attempt` counts consecutive failures, starting at 1
+    fn reconnect(&self, _attempt: u32) {}
+
+    /// A text message arrived on a WebSocket channel (`"market"` or `"user"`)
+    fn message_received(&self, _channel: &'static str) {}
+}
+
+static METRICS: OnceLock<Box<dyn Metrics>> = OnceLock::new();
+
+/// Install the global metrics receiver
+///
+/// Can only be called once per process; later calls return [`Error::Config`].
+pub fn set_metrics(metrics: impl Metrics + 'static) -> Result<()> {
+    METRICS
+        .set(Box::new(metrics))
+        .map_err(|_| Error::Config("Metrics receiver already set".to_string()))
+}
+
+/// Run `f` against the installed receiver, if any
+pub(crate) fn record(f: impl FnOnce(&dyn Metrics)) {
+    if let Some(metrics) = METRICS.get() {
+        f(metrics.as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use crate::ClobClient;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct Counters {
+        sent: AtomicU64,
+        failed: AtomicU64,
+    }
+
+    // Other tests share the global receiver, so only count this test's path
+    const PATH: &str = "/markets/0xmetrics";
+
+    impl Metrics for Arc<Counters> {
+        fn request_sent(&self, method: &str, path: &str) {
+            if (method, path) == ("GET", PATH) {
+                self.sent.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn request_failed(&self, method: &str, path: &str, error: &Error) {
+            if (method, path, error.kind()) == ("GET", PATH, "api") {
+                self.failed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_requests_are_recorded() {
+        let counters = Arc::new(Counters::default());
+        set_metrics(Arc::clone(&counters)).unwrap();
+        assert!(set_metrics(Arc::clone(&counters)).is_err());
+
+        let mock = MockTransport::new().on("GET", PATH, 404, "not found");
+        let client = ClobClient::new("https://clob.example").with_transport(mock);
+        assert!(client.get_market(&"0xmetrics".into()).await.is_err());
+
+        assert_eq!(counters.sent.load(Ordering::Relaxed), 1);
+        assert_eq!(counters.failed.load(Ordering::Relaxed), 1);
+    }
+}
@@ -1,33 +1,79 @@
-use super::rounding::{decimal_to_token_u64, fix_amount_rounding, ROUNDING_CONFIG};
-use crate::config::get_contract_config;
+use super::rounding::{decimal_to_token_u64, MAX_TOKEN_DECIMALS, ROUNDING_CONFIG, USDC_DECIMALS};
 use crate::error::{Error, Result};
-use crate::orders::RoundConfig;
-use crate::signing::{sign_order_message, EthSigner, Order};
+use crate::orders::{RoundConfig, RoundingStrategy, SystemTimeSource, TimeSource};
+use crate::signing::{
+    compute_proxy_address, sign_order_message, EthSigner, ExchangeVariant, Order,
+};
 use crate::types::{
     CreateOrderOptions, ExtraOrderArgs, MarketOrderArgs, OrderArgs, Side, SignatureType,
     SignedOrderRequest,
 };
-use crate::utils::get_current_unix_time_secs;
 use alloy_primitives::{Address, U256};
 use rand::{thread_rng, Rng};
 use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy::ToZero;
 use std::str::FromStr;
+use std::time::Duration;
 
-/// Generate a random seed for order salt
-fn generate_seed() -> Result<u64> {
+/// Generate a random seed for order salt from the current timestamp
+fn generate_seed(timestamp: u64) -> u64 {
     let mut rng = thread_rng();
     let y: f64 = rng.gen();
-    let timestamp = get_current_unix_time_secs()?;
     let a: f64 = timestamp as f64 * y;
-    Ok(a as u64)
+    a as u64
+}
+
+/// Largest fee rate accepted when signing an order
+///
+/// Matches the exchange contract's `MAX_FEE_RATE_BIPS` (10%); orders with a
+/// higher rate can never be matched.
+pub const MAX_FEE_RATE_BPS: u32 = 1000;
+
+/// Security buffer in seconds the exchange requires on GTD expirations
+///
+/// Polymarket only accepts GTD orders that expire at least one minute from
+/// now; [`OrderBuilder::create_order`] adds this to the requested expiration.
+pub const GTD_EXPIRATION_BUFFER_SECS: u64 = 60;
+
+/// Largest expiration treated as Unix seconds (year 3000); larger values are
+/// almost certainly milliseconds
+const MAX_EXPIRATION_SECS: u64 = 32_503_680_000;
+
+/// Resolve the fee rate for an order, preferring the per-order override
+fn resolve_fee_rate_bps(extras: &ExtraOrderArgs, options: &CreateOrderOptions) -> Result<u32> {
+    let fee_rate_bps = extras.fee_rate_bps.or(options.fee_rate_bps).unwrap_or(0);
+    if fee_rate_bps > MAX_FEE_RATE_BPS {
+        return Err(Error::InvalidOrder(format!(
+            "Fee rate {} bps exceeds the maximum of {} bps",
+            fee_rate_bps, MAX_FEE_RATE_BPS
+        )));
+    }
+    Ok(fee_rate_bps)
+}
+
+/// Rounding configuration and neg risk flag from the order options
+fn resolve_options(options: &CreateOrderOptions) -> Result<(&'static RoundConfig, bool)> {
+    let (tick_size, neg_risk) = options.required()?;
+
+    let round_config = ROUNDING_CONFIG
+        .get(&tick_size)
+        .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
+
+    Ok((round_config, neg_risk))
 }
 
+/// Function deriving an order salt from the current timestamp
+type SaltGenerator = Box<dyn Fn(u64) -> u64 + Send + Sync>;
+
 /// Builder for creating and signing orders
 pub struct OrderBuilder {
     signer: Box<dyn EthSigner>,
     sig_type: SignatureType,
-    funder: Address,
+    /// Explicit funder; derived per chain when unset
+    funder: Option<Address>,
+    collateral_decimals: u32,
+    time_source: Box<dyn TimeSource>,
+    salt_generator: Option<SaltGenerator>,
 }
 
 impl OrderBuilder {
@@ -36,23 +82,98 @@ impl OrderBuilder {
     /// # Arguments
     /// * `signer` - The Ethereum signer to use for signing orders
     /// * `sig_type` - The signature type (defaults to EOA if None)
-    /// * `funder` - The address funding the order (defaults to signer address if None)
+    /// * `funder` - The address funding the order (defaults to the wallet
+    ///   derived for `sig_type` if None)
+    ///
+    /// Orders are signed by the signer and carry `sig_type` as their
+    /// `signatureType`; the funder is the order maker. Without an explicit
+    /// funder, the maker is the signer address for [`SignatureType::Eoa`] and
+    /// the proxy wallet or Gnosis Safe derived by [`compute_proxy_address`]
+    /// for [`SignatureType::PolyProxy`] and [`SignatureType::PolyGnosisSafe`],
+    /// on the chain each order is created for. Only Polygon mainnet wallets
+    /// can be derived; on other chains, creating orders for proxy or Safe
+    /// wallets fails with [`Error::Config`] unless the funder is passed.
+    ///
+    /// The funder can be overridden per order with [`ExtraOrderArgs::maker`].
     pub fn new(
         signer: impl EthSigner + 'static,
         sig_type: Option<SignatureType>,
         funder: Option<Address>,
     ) -> Self {
         let sig_type = sig_type.unwrap_or(SignatureType::Eoa);
-        let signer_addr = signer.address();
-        let funder = funder.unwrap_or(signer_addr);
 
         Self {
             signer: Box::new(signer),
             sig_type,
             funder,
+            collateral_decimals: USDC_DECIMALS,
+            time_source: Box::new(SystemTimeSource),
+            salt_generator: None,
         }
     }
 
+    /// Read the current time from `time_source` instead of the system clock
+    ///
+    /// The time feeds the order salt. Use an [`OffsetTimeSource`](crate::orders::OffsetTimeSource)
+    /// to correct for clock skew, or a [`FixedTimeSource`](crate::orders::FixedTimeSource)
+    /// (together with [`with_salt_generator`](Self::with_salt_generator)) for
+    /// reproducible orders in tests.
+    pub fn with_time_source(mut self, time_source: impl TimeSource + 'static) -> Self {
+        self.time_source = Box::new(time_source);
+        self
+    }
+
+    /// Derive order salts with `generator` instead of randomly
+    ///
+    /// `generator` receives the current timestamp from the time source. By
+    /// default the salt is the timestamp scaled by a random factor, which
+    /// makes signatures differ between otherwise identical orders. A
+    /// deterministic generator makes signing fully reproducible; salts must
+    /// still be unique per order in production, or the exchange rejects the
+    /// repeated order.
+    pub fn with_salt_generator(
+        mut self,
+        generator: impl Fn(u64) -> u64 + Send + Sync + 'static,
+    ) -> Self {
+        self.salt_generator = Some(Box::new(generator));
+        self
+    }
+
+    /// Convert order amounts with `decimals` instead of USDC's 6
+    ///
+    /// For deployments whose collateral token has other decimals, e.g. a
+    /// testnet token. Outcome token amounts use the same decimals, as
+    /// conditional tokens inherit them from their collateral. The collateral
+    /// address isn't part of the signed order; the exchange contract
+    /// determines it.
+    ///
+    /// Amounts in token units must fit in a `u64`, which limits orders to
+    /// about 18 whole tokens with 18 decimals; larger orders fail with
+    /// [`Error::InvalidOrder`].
+    ///
+    /// Balances are still read with USDC's decimals, see
+    /// [`BalanceAllowance::balance`](crate::types::BalanceAllowance::balance).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidParameter`] if `decimals` exceeds
+    /// [`MAX_TOKEN_DECIMALS`](crate::orders::MAX_TOKEN_DECIMALS).
+    pub fn with_collateral_decimals(mut self, decimals: u32) -> Result<Self> {
+        if decimals > MAX_TOKEN_DECIMALS {
+            return Err(Error::InvalidParameter(format!(
+                "Collateral decimals {} exceed the maximum of {}",
+                decimals, MAX_TOKEN_DECIMALS
+            )));
+        }
+        self.collateral_decimals = decimals;
+        Ok(self)
+    }
+
+    /// Decimals used to convert order amounts to token units
+    pub fn collateral_decimals(&self) -> u32 {
+        self.collateral_decimals
+    }
+
     /// Get the signature type as u8
     pub fn get_sig_type(&self) -> u8 {
         self.sig_type.to_u8()
@@ -65,29 +186,30 @@ impl OrderBuilder {
         size: Decimal,
         price: Decimal,
         round_config: &RoundConfig,
-    ) -> (u64, u64) {
+        rounding: RoundingStrategy,
+    ) -> Result<(u64, u64)> {
         // Use ToZero for prices to ensure they never round to 1.0 (invalid for prediction markets)
         let raw_price = price.round_dp_with_strategy(round_config.price, ToZero);
 
         match side {
             Side::Buy => {
-                let raw_taker_amt = size.round_dp_with_strategy(round_config.size, ToZero);
+                let raw_taker_amt = rounding.round(size, round_config.size);
                 let raw_maker_amt = raw_taker_amt * raw_price;
-                let raw_maker_amt = fix_amount_rounding(raw_maker_amt, round_config);
-                (
-                    decimal_to_token_u64(raw_maker_amt),
-                    decimal_to_token_u64(raw_taker_amt),
-                )
+                let raw_maker_amt = rounding.round_amount(raw_maker_amt, round_config);
+                Ok((
+                    decimal_to_token_u64(raw_maker_amt, self.collateral_decimals)?,
+                    decimal_to_token_u64(raw_taker_amt, self.collateral_decimals)?,
+                ))
             }
             Side::Sell => {
-                let raw_maker_amt = size.round_dp_with_strategy(round_config.size, ToZero);
+                let raw_maker_amt = rounding.round(size, round_config.size);
                 let raw_taker_amt = raw_maker_amt * raw_price;
-                let raw_taker_amt = fix_amount_rounding(raw_taker_amt, round_config);
+                let raw_taker_amt = rounding.round_amount(raw_taker_amt, round_config);
 
-                (
-                    decimal_to_token_u64(raw_maker_amt),
-                    decimal_to_token_u64(raw_taker_amt),
-                )
+                Ok((
+                    decimal_to_token_u64(raw_maker_amt, self.collateral_decimals)?,
+                    decimal_to_token_u64(raw_taker_amt, self.collateral_decimals)?,
+                ))
             }
         }
     }
@@ -99,8 +221,9 @@ impl OrderBuilder {
         amount: Decimal,
         price: Decimal,
         round_config: &RoundConfig,
-    ) -> (u64, u64) {
-        let raw_maker_amt = amount.round_dp_with_strategy(round_config.size, ToZero);
+        rounding: RoundingStrategy,
+    ) -> Result<(u64, u64)> {
+        let raw_maker_amt = rounding.round(amount, round_config.size);
         // Use ToZero for prices to ensure they never round to 1.0 (invalid for prediction markets)
         let raw_price = price.round_dp_with_strategy(round_config.price, ToZero);
 
@@ -109,12 +232,12 @@ impl OrderBuilder {
             Side::Sell => raw_maker_amt * raw_price,
         };
 
-        let raw_taker_amt = fix_amount_rounding(raw_taker_amt, round_config);
+        let raw_taker_amt = rounding.round_amount(raw_taker_amt, round_config);
 
-        (
-            decimal_to_token_u64(raw_maker_amt),
-            decimal_to_token_u64(raw_taker_amt),
-        )
+        Ok((
+            decimal_to_token_u64(raw_maker_amt, self.collateral_decimals)?,
+            decimal_to_token_u64(raw_taker_amt, self.collateral_decimals)?,
+        ))
     }
 
     /// Create a market order
@@ -128,25 +251,20 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
-        let tick_size = options
-            .tick_size
-            .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
-
-        let neg_risk = options
-            .neg_risk
-            .ok_or_else(|| Error::MissingField("neg_risk".to_string()))?;
+        let (round_config, neg_risk) = resolve_options(&options)?;
 
-        let round_config = ROUNDING_CONFIG
-            .get(&tick_size)
-            .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
+        let fee_rate_bps = resolve_fee_rate_bps(extras, &options)?;
 
-        let (maker_amount, taker_amount) =
-            self.get_market_order_amounts(order_args.side, order_args.amount, price, round_config);
-
-        let contract_config = get_contract_config(chain_id, neg_risk)?;
+        let (maker_amount, taker_amount) = self.get_market_order_amounts(
+            order_args.side,
+            order_args.amount,
+            price,
+            round_config,
+            options.rounding,
+        )?;
 
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address =
+            ExchangeVariant::from_neg_risk(neg_risk).exchange_address(chain_id)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
@@ -156,13 +274,77 @@ impl OrderBuilder {
             maker_amount,
             taker_amount,
             0, // Market orders have 0 expiration
+            fee_rate_bps,
             extras,
         )
     }
 
+    /// Check limit order arguments against `options` without building or signing
+    ///
+    /// Runs the same local checks as [`create_order`](Self::create_order):
+    /// the tick size must be supported and the neg risk flag set, the token
+    /// id must be a decimal integer, the price must be a multiple of the tick
+    /// size between one tick and one minus one tick, and the size must stay
+    /// positive at share precision (rounded with `options.rounding`) and reach `options.min_order_size` if set.
+    ///
+    /// No network access is involved, so this is cheap enough to run on
+    /// every input change.
+    pub fn validate(&self, order_args: &OrderArgs, options: &CreateOrderOptions) -> Result<()> {
+        let (round_config, _) = resolve_options(options)?;
+        let tick_size = options.tick_size.unwrap_or_default();
+
+        U256::from_str_radix(&order_args.token_id, 10)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid token_id: {}", e)))?;
+
+        let price = order_args.price;
+        if price < tick_size || price > Decimal::ONE - tick_size {
+            return Err(Error::InvalidOrder(format!(
+                "Price {} is outside the valid range [{}, {}]",
+                price,
+                tick_size,
+                Decimal::ONE - tick_size
+            )));
+        }
+        if !(price % tick_size).is_zero() {
+            return Err(Error::InvalidOrder(format!(
+                "Price {} is not a multiple of the tick size {}",
+                price, tick_size
+            )));
+        }
+
+        let size = order_args.size;
+        if options.rounding.round(size, round_config.size) <= Decimal::ZERO {
+            return Err(Error::InvalidOrder(format!(
+                "Order size {} must be at least {}",
+                size,
+                Decimal::new(1, round_config.size)
+            )));
+        }
+        if let Some(min_order_size) = options.min_order_size {
+            if size < min_order_size {
+                return Err(Error::InvalidOrder(format!(
+                    "Order size {} is below the market minimum of {}",
+                    size, min_order_size
+                )));
+            }
+        }
+        Ok(())
+    }
+
     /// Create a limit order
     ///
-    /// Limit orders are executed at a specific price or better.
+    /// Limit orders are executed at a specific price or better. The arguments
+    /// are checked with [`validate`](Self::validate) first, which fails with
+    /// [`Error::MissingField`] if `options` lacks the tick size or neg risk
+    /// flag.
+    ///
+    /// `expiration` is 0 for orders that don't expire, or the Unix time in
+    /// **seconds** at which a GTD order should stop being live. The exchange
+    /// requires a security buffer of [`GTD_EXPIRATION_BUFFER_SECS`] on top of
+    /// the intended lifetime, which is added here, so pass `now + 30` for an
+    /// order meant to live 30 seconds, or set [`OrderArgs::expires_in`]
+    /// instead and leave `expiration` at 0. Expirations that look like
+    /// milliseconds or lie in the past are rejected with [`Error::InvalidOrder`].
     pub fn create_order(
         &self,
         chain_id: u64,
@@ -171,29 +353,26 @@ impl OrderBuilder {
         extras: &ExtraOrderArgs,
         options: CreateOrderOptions,
     ) -> Result<SignedOrderRequest> {
-        let tick_size = options
-            .tick_size
-            .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
-
-        let neg_risk = options
-            .neg_risk
-            .ok_or_else(|| Error::MissingField("neg_risk".to_string()))?;
+        self.validate(order_args, &options)?;
+        let (round_config, neg_risk) = resolve_options(&options)?;
+        let expiration = match order_args.expires_in {
+            Some(lifetime) => self.expiration_from_lifetime(lifetime, expiration)?,
+            None => expiration,
+        };
+        let expiration = self.gtd_expiration(expiration)?;
 
-        let round_config = ROUNDING_CONFIG
-            .get(&tick_size)
-            .ok_or_else(|| Error::InvalidParameter(format!("Invalid tick_size: {}", tick_size)))?;
+        let fee_rate_bps = resolve_fee_rate_bps(extras, &options)?;
 
         let (maker_amount, taker_amount) = self.get_order_amounts(
             order_args.side,
             order_args.size,
             order_args.price,
             round_config,
-        );
-
-        let contract_config = get_contract_config(chain_id, neg_risk)?;
+            options.rounding,
+        )?;
 
-        let exchange_address = Address::from_str(&contract_config.exchange)
-            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))?;
+        let exchange_address =
+            ExchangeVariant::from_neg_risk(neg_risk).exchange_address(chain_id)?;
 
         self.build_signed_order(
             order_args.token_id.clone(),
@@ -203,10 +382,49 @@ impl OrderBuilder {
             maker_amount,
             taker_amount,
             expiration,
+            fee_rate_bps,
             extras,
         )
     }
 
+    /// Absolute expiration for an order living `lifetime` from now
+    fn expiration_from_lifetime(&self, lifetime: Duration, expiration: u64) -> Result<u64> {
+        if expiration != 0 {
+            return Err(Error::InvalidOrder(
+                "Pass either an expiration or OrderArgs::expires_in, not both".to_string(),
+            ));
+        }
+        if lifetime.as_secs() == 0 {
+            return Err(Error::InvalidOrder(format!(
+                "Order lifetime {:?} must be at least one second",
+                lifetime
+            )));
+        }
+        Ok(self.time_source.now_secs()? + lifetime.as_secs())
+    }
+
+    /// Check a GTD expiration is in seconds and in the future, then add the buffer
+    fn gtd_expiration(&self, expiration: u64) -> Result<u64> {
+        if expiration == 0 {
+            return Ok(0);
+        }
+        if expiration > MAX_EXPIRATION_SECS {
+            return Err(Error::InvalidOrder(format!(
+                "Expiration {} looks like milliseconds; pass Unix seconds instead (e.g. {})",
+                expiration,
+                expiration / 1000
+            )));
+        }
+        let now = self.time_source.now_secs()?;
+        if expiration <= now {
+            return Err(Error::InvalidOrder(format!(
+                "Expiration {} is not in the future (now is {})",
+                expiration, now
+            )));
+        }
+        Ok(expiration + GTD_EXPIRATION_BUFFER_SECS)
+    }
+
     /// Build and sign an order
     #[allow(clippy::too_many_arguments)]
     fn build_signed_order(
@@ -218,18 +436,29 @@ impl OrderBuilder {
         maker_amount: u64,
         taker_amount: u64,
         expiration: u64,
+        fee_rate_bps: u32,
         extras: &ExtraOrderArgs,
     ) -> Result<SignedOrderRequest> {
-        let seed = generate_seed()?;
+        let timestamp = self.time_source.now_secs()?;
+        let seed = match &self.salt_generator {
+            Some(generator) => generator(timestamp),
+            None => generate_seed(timestamp),
+        };
         let taker_address = Address::from_str(&extras.taker)
             .map_err(|e| Error::InvalidParameter(format!("Invalid taker address: {}", e)))?;
 
         let u256_token_id = U256::from_str_radix(&token_id, 10)
             .map_err(|e| Error::InvalidParameter(format!("Invalid token_id: {}", e)))?;
 
+        // A per-order maker overrides the builder's default funder
+        let maker = match extras.maker.or(self.funder) {
+            Some(maker) => maker,
+            None => compute_proxy_address(self.signer.address(), self.sig_type, chain_id)?,
+        };
+
         let order = Order {
             salt: U256::from(seed),
-            maker: self.funder,
+            maker,
             signer: self.signer.address(),
             taker: taker_address,
             tokenId: u256_token_id,
@@ -237,7 +466,7 @@ impl OrderBuilder {
             takerAmount: U256::from(taker_amount),
             expiration: U256::from(expiration),
             nonce: extras.nonce,
-            feeRateBps: U256::from(extras.fee_rate_bps),
+            feeRateBps: U256::from(fee_rate_bps),
             side: side.to_u8(),
             signatureType: self.sig_type.to_u8(),
         };
@@ -246,7 +475,7 @@ impl OrderBuilder {
 
         Ok(SignedOrderRequest {
             salt: seed,
-            maker: self.funder.to_checksum(None),
+            maker: maker.to_checksum(None),
             signer: self.signer.address().to_checksum(None),
             taker: taker_address.to_checksum(None),
             token_id,
@@ -254,10 +483,11 @@ impl OrderBuilder {
             taker_amount: taker_amount.to_string(),
             expiration: expiration.to_string(),
             nonce: extras.nonce.to_string(),
-            fee_rate_bps: extras.fee_rate_bps.to_string(),
+            fee_rate_bps: fee_rate_bps.to_string(),
             side: side.as_str().to_string(),
             signature_type: self.sig_type.to_u8(),
             signature,
+            post_only: extras.post_only,
         })
     }
 }
@@ -265,12 +495,15 @@ impl OrderBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::orders::FixedTimeSource;
+    use crate::utils::get_current_unix_time_secs;
     use alloy_signer_local::PrivateKeySigner;
 
     #[test]
     fn test_generate_seed() {
-        let seed1 = generate_seed().unwrap();
-        let seed2 = generate_seed().unwrap();
+        let timestamp = get_current_unix_time_secs().unwrap();
+        let seed1 = generate_seed(timestamp);
+        let seed2 = generate_seed(timestamp);
         // Seeds should be different (very unlikely to be the same)
         assert_ne!(seed1, seed2);
     }
@@ -287,8 +520,15 @@ mod tests {
         let price = Decimal::from_str("0.999").unwrap();
         let size = Decimal::from_str("30.0").unwrap();
 
-        let (maker_amount, taker_amount) =
-            builder.get_order_amounts(Side::Sell, size, price, round_config);
+        let (maker_amount, taker_amount) = builder
+            .get_order_amounts(
+                Side::Sell,
+                size,
+                price,
+                round_config,
+                RoundingStrategy::Down,
+            )
+            .unwrap();
 
         // Verify amounts are NOT equal (which would mean price = 1.0)
         assert_ne!(
@@ -302,4 +542,311 @@ mod tests {
         assert_eq!(maker_amount, 30_000_000);
         assert_eq!(taker_amount, 27_000_000);
     }
+
+    #[test]
+    fn test_order_amounts_follow_rounding_strategy() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let round_config = &ROUNDING_CONFIG[&Decimal::new(1, 2)];
+        let size = Decimal::from_str("10.125").unwrap();
+        let price = Decimal::from_str("0.5").unwrap();
+        let amounts = |rounding| {
+            builder
+                .get_order_amounts(Side::Buy, size, price, round_config, rounding)
+                .unwrap()
+        };
+
+        // Maker amount is USDC spent, taker amount is shares received
+        assert_eq!(amounts(RoundingStrategy::Down), (5_060_000, 10_120_000));
+        assert_eq!(amounts(RoundingStrategy::Up), (5_065_000, 10_130_000));
+        assert_eq!(amounts(RoundingStrategy::Nearest), (5_065_000, 10_130_000));
+    }
+
+    #[test]
+    fn test_collateral_decimals() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_collateral_decimals(18)
+            .unwrap();
+        let round_config = ROUNDING_CONFIG.get(&Decimal::new(1, 2)).unwrap();
+        let amounts = |size: u32| {
+            builder.get_order_amounts(
+                Side::Buy,
+                Decimal::from(size),
+                Decimal::new(5, 1),
+                round_config,
+                RoundingStrategy::Down,
+            )
+        };
+        let (maker_amount, taker_amount) = amounts(10).unwrap();
+        assert_eq!(maker_amount, 5_000_000_000_000_000_000);
+        assert_eq!(taker_amount, 10_000_000_000_000_000_000);
+        // 100 shares are 10^20 units, past u64::MAX
+        assert!(matches!(amounts(100), Err(Error::InvalidOrder(_))));
+
+        let too_many = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_collateral_decimals(MAX_TOKEN_DECIMALS + 1);
+        assert!(matches!(too_many, Err(Error::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_maker_override() {
+        let signer = PrivateKeySigner::random();
+        let signer_addr = signer.address();
+        let funder = Address::repeat_byte(0x11);
+        let other_funder = Address::repeat_byte(0x22);
+        let builder = OrderBuilder::new(signer, Some(SignatureType::PolyProxy), Some(funder));
+
+        let order_args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+
+        let extras = ExtraOrderArgs::default();
+        let order = builder
+            .create_order(137, &order_args, 0, &extras, options.clone())
+            .unwrap();
+        assert_eq!(order.maker, funder.to_checksum(None));
+
+        let extras = ExtraOrderArgs::new().maker(other_funder);
+        let order = builder
+            .create_order(137, &order_args, 0, &extras, options)
+            .unwrap();
+        assert_eq!(order.maker, other_funder.to_checksum(None));
+        assert_eq!(order.signer, signer_addr.to_checksum(None));
+    }
+
+    #[test]
+    fn test_signature_types() {
+        let order_args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let extras = ExtraOrderArgs::default();
+
+        for (sig_type, value) in [
+            (SignatureType::Eoa, 0),
+            (SignatureType::PolyProxy, 1),
+            (SignatureType::PolyGnosisSafe, 2),
+        ] {
+            let signer = PrivateKeySigner::random();
+            let signer_addr = signer.address();
+            let builder = OrderBuilder::new(signer, Some(sig_type), None);
+            let order = builder
+                .create_order(137, &order_args, 0, &extras, options.clone())
+                .unwrap();
+
+            let maker = compute_proxy_address(signer_addr, sig_type, 137).unwrap();
+            assert_eq!(order.maker, maker.to_checksum(None));
+            assert_eq!(order.signer, signer_addr.to_checksum(None));
+
+            let json = order
+                .to_submission_json("owner", crate::types::OrderType::Gtc)
+                .unwrap();
+            assert_eq!(json["order"]["signatureType"], value);
+        }
+
+        // No known wallet factories on Amoy: the funder must be passed
+        let builder = OrderBuilder::new(
+            PrivateKeySigner::random(),
+            Some(SignatureType::PolyProxy),
+            None,
+        );
+        let result = builder.create_order(80002, &order_args, 0, &extras, options.clone());
+        assert!(matches!(result, Err(Error::Config(_))));
+
+        let funder = Address::repeat_byte(0x33);
+        let builder = OrderBuilder::new(
+            PrivateKeySigner::random(),
+            Some(SignatureType::PolyProxy),
+            Some(funder),
+        );
+        let order = builder
+            .create_order(80002, &order_args, 0, &extras, options)
+            .unwrap();
+        assert_eq!(order.maker, funder.to_checksum(None));
+    }
+
+    #[test]
+    fn test_deterministic_signing_with_fixed_time() {
+        let build = || {
+            let signer = PrivateKeySigner::from_str(
+                "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+            )
+            .unwrap();
+            OrderBuilder::new(signer, None, None)
+                .with_time_source(FixedTimeSource(1_700_000_000))
+                .with_salt_generator(|timestamp| timestamp / 2)
+        };
+        let order_args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let extras = ExtraOrderArgs::default();
+
+        let order = build()
+            .create_order(137, &order_args, 0, &extras, options.clone())
+            .unwrap();
+        let again = build()
+            .create_order(137, &order_args, 0, &extras, options)
+            .unwrap();
+
+        assert_eq!(order.salt, 850_000_000);
+        assert_eq!(order.signature, again.signature);
+        // Fixed key, time and salt give a fixed EIP-712 hash and RFC 6979 signature
+        assert_eq!(
+            order.signature,
+            "0x070ae68963d22c330884ecc68aa78e177b140dce741cbfa08fa6324ffde9b20b14c6b3cd203ef46ba10c3a1785e0773a646cbbc65c22b7d013a4b05a5f9c903e1c"
+        );
+    }
+
+    #[test]
+    fn test_min_order_size_enforced() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false)
+            .min_order_size(Decimal::from(5));
+        let extras = ExtraOrderArgs::default();
+
+        let small = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(4), Side::Buy);
+        let result = builder.create_order(137, &small, 0, &extras, options.clone());
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+
+        let ok = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(5), Side::Buy);
+        assert!(builder.create_order(137, &ok, 0, &extras, options).is_ok());
+    }
+
+    #[test]
+    fn test_fee_rate_bps() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let order_args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+
+        // No fee anywhere signs a zero rate
+        let extras = ExtraOrderArgs::default();
+        let order = builder
+            .create_order(137, &order_args, 0, &extras, options.clone())
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "0");
+
+        // The market's base fee is the default, an explicit rate overrides it
+        let market_options = options.clone().fee_rate_bps(100);
+        let order = builder
+            .create_order(137, &order_args, 0, &extras, market_options.clone())
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "100");
+
+        let extras = ExtraOrderArgs::new().fee_rate_bps(25);
+        let market_args = MarketOrderArgs::new("1234", Decimal::from(10), Side::Buy);
+        let order = builder
+            .create_market_order(
+                137,
+                &market_args,
+                Decimal::new(5, 1),
+                &extras,
+                market_options,
+            )
+            .unwrap();
+        assert_eq!(order.fee_rate_bps, "25");
+
+        let extras = ExtraOrderArgs::new().fee_rate_bps(MAX_FEE_RATE_BPS + 1);
+        let result = builder.create_order(137, &order_args, 0, &extras, options);
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_validate() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let with_min = options.clone().min_order_size(Decimal::from(5));
+        let args = |price: &str, size: &str| {
+            let price = Decimal::from_str(price).unwrap();
+            let size = Decimal::from_str(size).unwrap();
+            OrderArgs::new("1234", price, size, Side::Buy)
+        };
+        let invalid = |args: OrderArgs, options: &CreateOrderOptions| {
+            matches!(
+                builder.validate(&args, options),
+                Err(Error::InvalidOrder(_))
+            )
+        };
+
+        assert!(builder.validate(&args("0.5", "10"), &with_min).is_ok());
+        assert!(builder.validate(&args("0.99", "5"), &with_min).is_ok());
+        // Off tick, out of range, below minimum, rounds to zero shares
+        assert!(invalid(args("0.505", "10"), &options));
+        assert!(invalid(args("1", "10"), &options));
+        assert!(invalid(args("0", "10"), &options));
+        assert!(invalid(args("0.5", "4"), &with_min));
+        assert!(invalid(args("0.5", "0.001"), &options));
+
+        let bad_token = OrderArgs::new("0xabc", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        assert!(matches!(
+            builder.validate(&bad_token, &options),
+            Err(Error::InvalidParameter(_))
+        ));
+        let no_neg_risk = CreateOrderOptions::new().tick_size(Decimal::new(1, 2));
+        assert!(matches!(
+            builder.validate(&args("0.5", "10"), &no_neg_risk),
+            Err(Error::MissingField(_))
+        ));
+
+        // create_order applies the same checks
+        let extras = ExtraOrderArgs::default();
+        let result = builder.create_order(137, &args("0.505", "10"), 0, &extras, options);
+        assert!(matches!(result, Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_gtd_expiration() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_time_source(FixedTimeSource(1_700_000_000));
+        let order_args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let extras = ExtraOrderArgs::default();
+        let create = |expiration| {
+            builder.create_order(137, &order_args, expiration, &extras, options.clone())
+        };
+
+        assert_eq!(create(0).unwrap().expiration, "0");
+        assert_eq!(create(1_700_000_030).unwrap().expiration, "1700000090");
+
+        let err = create(1_700_000_030_000).unwrap_err();
+        assert!(matches!(&err, Error::InvalidOrder(msg) if msg.contains("milliseconds")));
+        assert!(matches!(create(1_699_999_999), Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_expires_in() {
+        let builder = OrderBuilder::new(PrivateKeySigner::random(), None, None)
+            .with_time_source(FixedTimeSource(1_700_000_000));
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let extras = ExtraOrderArgs::default();
+        let create = |lifetime, expiration| {
+            let order_args =
+                OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy)
+                    .expires_in(lifetime);
+            builder.create_order(137, &order_args, expiration, &extras, options.clone())
+        };
+
+        // now + 1h + the 60 second buffer
+        let order = create(Duration::from_secs(3600), 0).unwrap();
+        assert_eq!(order.expiration, "1700003660");
+
+        assert!(matches!(
+            create(Duration::from_millis(500), 0),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            create(Duration::from_secs(3600), 1_700_000_030),
+            Err(Error::InvalidOrder(_))
+        ));
+    }
 }
@@ -1,7 +1,18 @@
 mod builder;
+mod nonce;
 mod price;
 mod rounding;
+mod time;
 
-pub use builder::OrderBuilder;
-pub use price::calculate_market_price;
-pub use rounding::{decimal_to_token_u64, fix_amount_rounding, RoundConfig, ROUNDING_CONFIG};
+pub use builder::{OrderBuilder, GTD_EXPIRATION_BUFFER_SECS, MAX_FEE_RATE_BPS};
+pub(crate) use nonce::{eth_call_request, nonces_call, EthCallResponse};
+pub use nonce::{increment_nonce_call, ExchangeCall};
+pub use price::{
+    available_liquidity, calculate_market_price, price_to_american_odds, price_to_decimal_odds,
+    price_to_implied_prob,
+};
+pub use rounding::{
+    decimal_to_token_u64, fix_amount_rounding, token_u64_to_decimal, RoundConfig, RoundingStrategy,
+    MAX_TOKEN_DECIMALS, ROUNDING_CONFIG, USDC_DECIMALS,
+};
+pub use time::{FixedTimeSource, OffsetTimeSource, SystemTimeSource, TimeSource};
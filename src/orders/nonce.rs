@@ -0,0 +1,139 @@
+use crate::error::{Error, Result};
+use crate::signing::ExchangeVariant;
+use alloy_primitives::{keccak256, Address, Bytes, U256};
+use serde::Deserialize;
+
+/// Contract call to be sent as a transaction or `eth_call`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeCall {
+    /// Exchange contract address
+    pub to: Address,
+    /// ABI-encoded calldata
+    pub data: Bytes,
+}
+
+/// Build the `incrementNonce()` call on the exchange contract
+///
+/// The exchange only accepts orders whose `nonce` equals the maker's current
+/// on-chain nonce, so incrementing it invalidates every order signed with the
+/// old nonce at once, including orders the operator no longer knows about.
+///
+/// The nonce is tracked per maker and incremented for `msg.sender`, so the
+/// transaction must be sent by the order maker: the EOA itself, or the proxy
+/// wallet for PolyProxy/Safe setups. Standard and neg risk markets use
+/// separate exchanges with separate nonces; invalidating both takes two calls.
+///
+/// This crate doesn't submit transactions; sign and send the call with your
+/// own provider. Afterwards, build new orders with the new nonce via
+/// [`ExtraOrderArgs::nonce`](crate::types::ExtraOrderArgs::nonce).
+pub fn increment_nonce_call(chain_id: u64, neg_risk: bool) -> Result<ExchangeCall> {
+    Ok(ExchangeCall {
+        to: exchange_address(chain_id, neg_risk)?,
+        data: selector("incrementNonce()").to_vec().into(),
+    })
+}
+
+/// Build the `nonces(maker)` view call on the exchange contract
+pub(crate) fn nonces_call(chain_id: u64, neg_risk: bool, maker: Address) -> Result<ExchangeCall> {
+    let mut data = selector("nonces(address)").to_vec();
+    data.extend_from_slice(maker.into_word().as_slice());
+    Ok(ExchangeCall {
+        to: exchange_address(chain_id, neg_risk)?,
+        data: data.into(),
+    })
+}
+
+/// JSON-RPC request body for an `eth_call` against the latest block
+pub(crate) fn eth_call_request(call: &ExchangeCall) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_call",
+        "params": [{ "to": call.to, "data": call.data }, "latest"],
+    })
+}
+
+/// JSON-RPC response to an `eth_call`
+#[derive(Debug, Deserialize)]
+pub(crate) struct EthCallResponse {
+    result: Option<String>,
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl EthCallResponse {
+    /// Decode the result as a single `uint256`
+    pub(crate) fn into_u256(self) -> Result<U256> {
+        if let Some(error) = self.error {
+            return Err(Error::Api {
+                status: 200,
+                message: format!("RPC error {}: {}", error.code, error.message),
+            });
+        }
+        let result = self
+            .result
+            .ok_or_else(|| Error::MissingField("result".to_string()))?;
+        let hex = result.trim_start_matches("0x");
+        if hex.is_empty() {
+            return Err(Error::InvalidParameter(
+                "eth_call returned no data; is the exchange deployed on this chain?".to_string(),
+            ));
+        }
+        U256::from_str_radix(hex, 16)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid uint256 result: {}", e)))
+    }
+}
+
+fn exchange_address(chain_id: u64, neg_risk: bool) -> Result<Address> {
+    ExchangeVariant::from_neg_risk(neg_risk).exchange_address(chain_id)
+}
+
+fn selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::chains;
+
+    #[test]
+    fn test_increment_nonce_call() {
+        let call = increment_nonce_call(chains::POLYGON_MAINNET, false).unwrap();
+        assert_eq!(
+            call.to.to_checksum(None),
+            "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"
+        );
+        assert_eq!(call.data.to_string(), "0x627cdcb9");
+    }
+
+    #[test]
+    fn test_nonces_call_encodes_maker() {
+        let maker = Address::repeat_byte(0x11);
+        let call = nonces_call(chains::POLYGON_MAINNET, true, maker).unwrap();
+        assert_eq!(&call.data[..4], &[0x7e, 0xce, 0xbe, 0x00]);
+        assert_eq!(&call.data[4..16], &[0u8; 12]);
+        assert_eq!(&call.data[16..], maker.as_slice());
+    }
+
+    #[test]
+    fn test_decode_eth_call_response() {
+        let response: EthCallResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0", "id": 1, "result": "0x0000000000000000000000000000000000000000000000000000000000000003"}"#,
+        )
+        .unwrap();
+        assert_eq!(response.into_u256().unwrap(), U256::from(3));
+
+        let response: EthCallResponse = serde_json::from_str(
+            r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": -32000, "message": "execution reverted"}}"#,
+        )
+        .unwrap();
+        assert!(matches!(response.into_u256(), Err(Error::Api { .. })));
+    }
+}
@@ -13,7 +13,8 @@ use rust_decimal::Decimal;
 /// * `shares_to_match` - The number of shares to match
 ///
 /// # Returns
-/// The weighted average price at which the market order can be filled, or an error if there's insufficient liquidity
+/// The weighted average price at which the market order can be filled, or
+/// [`Error::InsufficientLiquidity`] with the fillable amount if the book is too thin
 ///
 /// # Example
 /// ```no_run
@@ -41,12 +42,12 @@ pub fn calculate_market_price(
     let positions = match side {
         Side::Buy => {
             let mut asks = positions.to_vec();
-            asks.sort_by(|a, b| a.price.cmp(&b.price));
+            asks.sort_by_key(|a| a.price);
             asks
         }
         Side::Sell => {
             let mut bids = positions.to_vec();
-            bids.sort_by(|a, b| b.price.cmp(&a.price));
+            bids.sort_by_key(|b| std::cmp::Reverse(b.price));
             bids
         }
     };
@@ -61,10 +62,78 @@ pub fn calculate_market_price(
         }
     }
 
-    Err(Error::InvalidOrder(format!(
-        "Not enough liquidity to create market order with amount {}",
-        shares_to_match
-    )))
+    Err(Error::InsufficientLiquidity {
+        requested: shares_to_match,
+        available: shares_to_match - remaining,
+    })
+}
+
+/// Total liquidity a market order on `side` can take from `positions`
+///
+/// Measured in the unit of [`MarketOrderArgs::amount`](crate::types::MarketOrderArgs::amount):
+/// for a buy, the USDC cost of every level (`price * size`), for a sell, the
+/// total size in shares.
+pub fn available_liquidity(positions: &[PriceLevel], side: Side) -> Decimal {
+    match side {
+        Side::Buy => positions.iter().map(|p| p.price * p.size).sum(),
+        Side::Sell => positions.iter().map(|p| p.size).sum(),
+    }
+}
+
+/// Implied probability of an outcome trading at `price`
+///
+/// A share pays out 1 USDC, so its price already is the market's implied
+/// probability. Prices outside `[0, 1]` (e.g. from arithmetic on book
+/// levels) are clamped into it.
+pub fn price_to_implied_prob(price: Decimal) -> Decimal {
+    price.clamp(Decimal::ZERO, Decimal::ONE).normalize()
+}
+
+/// Decimal (European) odds for an outcome trading at `price`: the total
+/// payout per unit staked, `1 / price`
+///
+/// The price is clamped like in [`price_to_implied_prob`]. Returns `None`
+/// for a price of 0, where the odds are infinite.
+///
+/// ```
+/// use polymarket_rs::orders::price_to_decimal_odds;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(price_to_decimal_odds(dec!(0.25)), Some(dec!(4)));
+/// assert_eq!(price_to_decimal_odds(dec!(0)), None);
+/// ```
+pub fn price_to_decimal_odds(price: Decimal) -> Option<Decimal> {
+    let prob = price_to_implied_prob(price);
+    (!prob.is_zero()).then(|| (Decimal::ONE / prob).normalize())
+}
+
+/// American (moneyline) odds for an outcome trading at `price`
+///
+/// Favourites (price above 0.5) get negative odds, the stake needed to win
+/// 100; underdogs get positive odds, the profit on a stake of 100. A price of
+/// exactly 0.5 gives +100. Not rounded, so round for display.
+///
+/// The price is clamped like in [`price_to_implied_prob`]. Returns `None`
+/// for prices of 0 and 1, where the odds are infinite.
+///
+/// ```
+/// use polymarket_rs::orders::price_to_american_odds;
+/// use rust_decimal_macros::dec;
+///
+/// assert_eq!(price_to_american_odds(dec!(0.8)), Some(dec!(-400)));
+/// assert_eq!(price_to_american_odds(dec!(0.2)), Some(dec!(400)));
+/// ```
+pub fn price_to_american_odds(price: Decimal) -> Option<Decimal> {
+    let prob = price_to_implied_prob(price);
+    if prob.is_zero() || prob == Decimal::ONE {
+        return None;
+    }
+    let odds = if prob > Decimal::new(5, 1) {
+        -Decimal::ONE_HUNDRED * prob / (Decimal::ONE - prob)
+    } else {
+        Decimal::ONE_HUNDRED * (Decimal::ONE - prob) / prob
+    };
+    Some(odds.normalize())
 }
 
 #[cfg(test)]
@@ -107,6 +176,40 @@ mod tests {
     fn test_insufficient_liquidity() {
         let positions = vec![order(dec!(0.50), dec!(10))];
         let result = calculate_market_price(&positions, dec!(20), Side::Buy);
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(Error::InsufficientLiquidity { available, .. }) if available == dec!(10)
+        ));
+    }
+
+    #[test]
+    fn test_available_liquidity() {
+        let positions = vec![order(dec!(0.50), dec!(10)), order(dec!(0.60), dec!(2.5))];
+        // USDC for buys, shares for sells
+        assert_eq!(available_liquidity(&positions, Side::Buy), dec!(6.5));
+        assert_eq!(available_liquidity(&positions, Side::Sell), dec!(12.5));
+        assert_eq!(available_liquidity(&[], Side::Buy), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_price_to_odds() {
+        assert_eq!(price_to_implied_prob(dec!(0.650)), dec!(0.65));
+        assert_eq!(price_to_implied_prob(dec!(1.2)), Decimal::ONE);
+        assert_eq!(price_to_implied_prob(dec!(-0.1)), Decimal::ZERO);
+
+        assert_eq!(price_to_decimal_odds(dec!(0.5)), Some(dec!(2)));
+        assert_eq!(
+            price_to_decimal_odds(dec!(0.3)).map(|o| o.round_dp(4)),
+            Some(dec!(3.3333))
+        );
+        assert_eq!(price_to_decimal_odds(dec!(1)), Some(dec!(1)));
+        assert_eq!(price_to_decimal_odds(dec!(0)), None);
+
+        assert_eq!(price_to_american_odds(dec!(0.5)), Some(dec!(100)));
+        assert_eq!(price_to_american_odds(dec!(0.75)), Some(dec!(-300)));
+        assert_eq!(price_to_american_odds(dec!(0.25)), Some(dec!(300)));
+        assert_eq!(price_to_american_odds(dec!(0)), None);
+        assert_eq!(price_to_american_odds(dec!(1)), None);
+        assert_eq!(price_to_american_odds(dec!(1.5)), None);
     }
 }
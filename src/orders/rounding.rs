@@ -1,9 +1,13 @@
 use rust_decimal::Decimal;
-use rust_decimal::RoundingStrategy::{AwayFromZero, MidpointTowardZero, ToZero};
+use rust_decimal::RoundingStrategy::{
+    AwayFromZero, MidpointAwayFromZero, MidpointTowardZero, ToZero,
+};
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
+use crate::error::{Error, Result};
+
 /// Rounding configuration for a specific tick size
 #[derive(Debug, Clone, Copy)]
 pub struct RoundConfig {
@@ -12,6 +16,62 @@ pub struct RoundConfig {
     pub amount: u32,
 }
 
+/// How order sizes and amounts are rounded to the exchange's precision
+///
+/// Applies to the share size of limit orders and the amount of market orders
+/// (both rounded to [`RoundConfig::size`] decimals), and to the amount derived
+/// from them at the order price ([`RoundConfig::amount`] decimals). Prices are
+/// always rounded toward zero so they never reach 1.
+///
+/// The order's maker amount is what the exchange takes from the wallet:
+/// USDC for buys and shares for sells. Only [`Down`](Self::Down) guarantees
+/// it never exceeds what was asked for, so that sizing an order to the full
+/// balance can't fail for insufficient balance or allowance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RoundingStrategy {
+    /// Truncate toward zero (the default)
+    ///
+    /// The order never spends more than requested; it may buy or sell
+    /// slightly less.
+    #[default]
+    Down,
+    /// Round to the nearest value, halves away from zero
+    ///
+    /// Keeps the order closest to the requested size, but rounding up can
+    /// push the maker amount past the available balance.
+    Nearest,
+    /// Round away from zero
+    ///
+    /// The order never trades less than requested, but the maker amount can
+    /// exceed the available balance by up to one unit of precision; leave
+    /// headroom when sizing to the full balance.
+    Up,
+}
+
+impl RoundingStrategy {
+    /// Round a size or amount to `dp` decimals
+    pub fn round(self, value: Decimal, dp: u32) -> Decimal {
+        value.round_dp_with_strategy(
+            dp,
+            match self {
+                RoundingStrategy::Down => ToZero,
+                RoundingStrategy::Nearest => MidpointAwayFromZero,
+                RoundingStrategy::Up => AwayFromZero,
+            },
+        )
+    }
+
+    /// Round an amount derived from a size and a price
+    ///
+    /// `Down` keeps the historical [`fix_amount_rounding`] behavior.
+    pub fn round_amount(self, amt: Decimal, round_config: &RoundConfig) -> Decimal {
+        match self {
+            RoundingStrategy::Down => fix_amount_rounding(amt, round_config),
+            _ => self.round(amt, round_config.amount),
+        }
+    }
+}
+
 /// Rounding configurations for different tick sizes
 pub static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::new(|| {
     HashMap::from([
@@ -50,13 +110,55 @@ pub static ROUNDING_CONFIG: LazyLock<HashMap<Decimal, RoundConfig>> = LazyLock::
     ])
 });
 
-/// Convert decimal amount to token units (multiply by 1e6 and round)
-pub fn decimal_to_token_u64(amt: Decimal) -> u64 {
-    let mut amt = Decimal::from_scientific("1e6").expect("1e6 is not scientific") * amt;
+/// Decimals of USDC, Polymarket's collateral token
+///
+/// Conditional (outcome) tokens inherit the decimals of their collateral.
+pub const USDC_DECIMALS: u32 = 6;
+
+/// Highest number of token decimals amounts can be converted with
+///
+/// The precision of `Decimal`.
+pub const MAX_TOKEN_DECIMALS: u32 = Decimal::MAX_SCALE;
+
+/// Convert decimal amount to token units (multiply by `10^decimals` and round)
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidOrder`] if the amount in token units doesn't fit
+/// in a `u64`, e.g. a 100 token amount with 18 decimals, and
+/// [`Error::InvalidParameter`] if `decimals` exceeds [`MAX_TOKEN_DECIMALS`].
+pub fn decimal_to_token_u64(amt: Decimal, decimals: u32) -> Result<u64> {
+    if decimals > MAX_TOKEN_DECIMALS {
+        return Err(Error::InvalidParameter(format!(
+            "Token decimals {} exceed the maximum of {}",
+            decimals, MAX_TOKEN_DECIMALS
+        )));
+    }
+    let overflow = || {
+        Error::InvalidOrder(format!(
+            "Amount {} doesn't fit in token units with {} decimals",
+            amt, decimals
+        ))
+    };
+    let mut amt = Decimal::from_i128_with_scale(10i128.pow(decimals), 0)
+        .checked_mul(amt)
+        .ok_or_else(overflow)?;
     if amt.scale() > 0 {
         amt = amt.round_dp_with_strategy(0, MidpointTowardZero);
     }
-    amt.try_into().expect("Couldn't round decimal to integer")
+    amt.try_into().map_err(|_| overflow())
+}
+
+/// Convert token units back to a decimal amount (divide by `10^decimals`)
+///
+/// Inverse of [`decimal_to_token_u64`] for amounts with at most `decimals`
+/// decimals.
+///
+/// # Panics
+///
+/// Panics if `decimals` exceeds [`MAX_TOKEN_DECIMALS`].
+pub fn token_u64_to_decimal(amt: u64, decimals: u32) -> Decimal {
+    Decimal::from_i128_with_scale(amt.into(), decimals).normalize()
 }
 
 /// Fix amount rounding to ensure proper precision
@@ -82,9 +184,102 @@ mod tests {
         assert!(ROUNDING_CONFIG.contains_key(&Decimal::from_str("0.0001").unwrap()));
     }
 
+    #[test]
+    fn test_token_to_decimal() {
+        assert_eq!(
+            token_u64_to_decimal(1_500_000, USDC_DECIMALS),
+            Decimal::from_str("1.5").unwrap()
+        );
+        assert_eq!(
+            token_u64_to_decimal(1, USDC_DECIMALS),
+            Decimal::from_str("0.000001").unwrap()
+        );
+        assert_eq!(token_u64_to_decimal(0, USDC_DECIMALS), Decimal::ZERO);
+        assert_eq!(
+            token_u64_to_decimal(u64::MAX, USDC_DECIMALS),
+            Decimal::from_str("18446744073709.551615").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_token_round_trip() {
+        for x in ["0", "0.000001", "0.5", "1", "1.5", "12.345678", "1000000"] {
+            let x = Decimal::from_str(x).unwrap();
+            assert_eq!(
+                token_u64_to_decimal(
+                    decimal_to_token_u64(x, USDC_DECIMALS).unwrap(),
+                    USDC_DECIMALS
+                ),
+                x
+            );
+        }
+    }
+
+    #[test]
+    fn test_rounding_strategies() {
+        let config = ROUNDING_CONFIG
+            .get(&Decimal::from_str("0.01").unwrap())
+            .unwrap();
+        let size = Decimal::from_str("10.125").unwrap();
+        assert_eq!(
+            RoundingStrategy::Down.round(size, config.size).to_string(),
+            "10.12"
+        );
+        assert_eq!(
+            RoundingStrategy::Nearest
+                .round(size, config.size)
+                .to_string(),
+            "10.13"
+        );
+        assert_eq!(
+            RoundingStrategy::Up.round(size, config.size).to_string(),
+            "10.13"
+        );
+
+        let amount = Decimal::from_str("1.23451").unwrap();
+        assert_eq!(
+            RoundingStrategy::Down
+                .round_amount(amount, config)
+                .to_string(),
+            "1.2345"
+        );
+        assert_eq!(
+            RoundingStrategy::Up
+                .round_amount(amount, config)
+                .to_string(),
+            "1.2346"
+        );
+    }
+
     #[test]
     fn test_decimal_to_token() {
-        let result = decimal_to_token_u64(Decimal::from_str("1.5").unwrap());
-        assert_eq!(result, 1_500_000);
+        let result = decimal_to_token_u64(Decimal::from_str("1.5").unwrap(), USDC_DECIMALS);
+        assert_eq!(result.unwrap(), 1_500_000);
+
+        // Collateral with other decimals, e.g. an 18-decimal testnet token
+        let amount = Decimal::from_str("1.5").unwrap();
+        assert_eq!(
+            decimal_to_token_u64(amount, 18).unwrap(),
+            1_500_000_000_000_000_000
+        );
+        assert_eq!(token_u64_to_decimal(1_500_000_000_000_000_000, 18), amount);
+        assert_eq!(
+            decimal_to_token_u64(Decimal::from_str("1.555").unwrap(), 2).unwrap(),
+            155
+        );
+
+        // Too large for a u64 or for Decimal, rather than panicking
+        assert!(matches!(
+            decimal_to_token_u64(Decimal::from(100), 18),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            decimal_to_token_u64(Decimal::MAX, 6),
+            Err(Error::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            decimal_to_token_u64(amount, 29),
+            Err(Error::InvalidParameter(_))
+        ));
     }
 }
@@ -0,0 +1,68 @@
+use crate::error::Result;
+use crate::utils::get_current_unix_time_secs;
+
+/// Source of the current time used when building orders
+///
+/// [`OrderBuilder`](crate::OrderBuilder) reads the time once per order to
+/// derive the order salt. The default is [`SystemTimeSource`]; use
+/// [`FixedTimeSource`] for reproducible tests or [`OffsetTimeSource`] to
+/// correct for clock skew against the server.
+pub trait TimeSource: Send + Sync {
+    /// Current Unix timestamp in seconds
+    fn now_secs(&self) -> Result<u64>;
+}
+
+/// Local system clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now_secs(&self) -> Result<u64> {
+        get_current_unix_time_secs()
+    }
+}
+
+/// Always returns the same timestamp
+#[derive(Debug, Clone, Copy)]
+pub struct FixedTimeSource(pub u64);
+
+impl TimeSource for FixedTimeSource {
+    fn now_secs(&self) -> Result<u64> {
+        Ok(self.0)
+    }
+}
+
+/// System clock shifted by a fixed number of seconds
+///
+/// Measure the offset as server time minus local time, e.g. from
+/// [`ClobClient::get_server_time`](crate::ClobClient::get_server_time).
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetTimeSource {
+    offset_secs: i64,
+}
+
+impl OffsetTimeSource {
+    pub fn new(offset_secs: i64) -> Self {
+        Self { offset_secs }
+    }
+}
+
+impl TimeSource for OffsetTimeSource {
+    fn now_secs(&self) -> Result<u64> {
+        let now = get_current_unix_time_secs()?;
+        Ok(now.saturating_add_signed(self.offset_secs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_time_source() {
+        let now = SystemTimeSource.now_secs().unwrap();
+        let ahead = OffsetTimeSource::new(3600).now_secs().unwrap();
+        assert!(ahead >= now + 3600 && ahead <= now + 3601);
+        assert_eq!(FixedTimeSource(42).now_secs().unwrap(), 42);
+    }
+}
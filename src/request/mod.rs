@@ -4,4 +4,4 @@ mod pagination;
 
 pub use data_params::{ActivityQueryParams, ActivitySortBy, SortDirection, TradeQueryParams};
 pub use gamma_params::GammaMarketParams;
-pub use pagination::{PaginationParams, END_CURSOR, INITIAL_CURSOR};
+pub use pagination::{Cursor, PaginationParams, END_CURSOR, INITIAL_CURSOR};
@@ -1,7 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
 /// Pagination cursors for API requests
 pub const END_CURSOR: &str = "LTE=";
 pub const INITIAL_CURSOR: &str = "MA==";
 
+/// Opaque pagination cursor returned by the CLOB API
+///
+/// Cursors are base64 offsets; [`END_CURSOR`] (`"LTE="`, i.e. `-1`) marks
+/// the last page. Paginated responses expose the cursor of the following
+/// page through a `next_cursor()` method that returns `None` on the last
+/// page, so callers can loop until it does:
+///
+/// ```no_run
+/// use polymarket_rs::request::PaginationParams;
+/// use polymarket_rs::ClobClient;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let client = ClobClient::new("https://clob.polymarket.com");
+/// let mut pagination = PaginationParams::new();
+/// loop {
+///     let page = client.get_simplified_markets(Some(pagination)).await?;
+///     println!("{} markets", page.data.len());
+///     match page.next_cursor() {
+///         Some(cursor) => pagination = PaginationParams::with_cursor(cursor),
+///         None => break,
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    pub fn new(cursor: impl Into<String>) -> Self {
+        Self(cursor.into())
+    }
+
+    /// Cursor of the first page
+    pub fn initial() -> Self {
+        Self::new(INITIAL_CURSOR)
+    }
+
+    /// Whether this cursor marks the end of the results
+    ///
+    /// Empty cursors are treated as the end too.
+    pub fn is_end(&self) -> bool {
+        self.0.is_empty() || self.0 == END_CURSOR
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Cursor of the following page from a response's raw `next_cursor`,
+    /// or None if there are no more pages
+    pub(crate) fn next(raw: Option<&str>) -> Option<Self> {
+        raw.map(Self::new).filter(|cursor| !cursor.is_end())
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(s: String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(cursor: Cursor) -> Self {
+        cursor.0
+    }
+}
+
 /// Pagination parameters for list endpoints
 #[derive(Debug, Clone)]
 pub struct PaginationParams {
@@ -53,4 +137,18 @@ mod tests {
         assert_eq!(query.len(), 1);
         assert_eq!(query[0].0, "next_cursor");
     }
+
+    #[test]
+    fn test_cursor_end_detection() {
+        assert!(Cursor::new(END_CURSOR).is_end());
+        assert!(Cursor::new("").is_end());
+        assert!(!Cursor::initial().is_end());
+
+        assert_eq!(Cursor::next(Some("MTAw")), Some(Cursor::new("MTAw")));
+        assert_eq!(Cursor::next(Some(END_CURSOR)), None);
+        assert_eq!(Cursor::next(None), None);
+
+        let params = PaginationParams::with_cursor(Cursor::new("MTAw"));
+        assert_eq!(params.next_cursor.as_deref(), Some("MTAw"));
+    }
 }
@@ -1,6 +1,8 @@
-use crate::error::Result;
-use alloy_primitives::{hex::encode_prefixed, Address, U256};
+use crate::error::{Error, Result};
+use crate::types::{Side, SignedOrderRequest};
+use alloy_primitives::{hex::encode_prefixed, Address, PrimitiveSignature, B256, U256};
 use alloy_sol_types::{eip712_domain, sol, SolStruct};
+use std::str::FromStr;
 
 // EIP-712 struct for CLOB authentication
 sol! {
@@ -43,24 +45,30 @@ pub fn sign_clob_auth_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
-    let message = "This message attests that I control the given wallet".to_owned();
-
-    let auth_struct = ClobAuth {
-        address: signer.address(),
-        timestamp,
-        nonce,
-        message,
-    };
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
+    let signature = signer
+        .sign_hash_sync(&hash)
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
 
-    let domain = eip712_domain!(
-        name: "ClobAuthDomain",
-        version: "1",
-        chain_id: chain_id,
-    );
+    Ok(encode_prefixed(signature.as_bytes()))
+}
 
-    let hash = auth_struct.eip712_signing_hash(&domain);
+/// Signs a CLOB authentication message using EIP-712 with an async signer
+///
+/// Async counterpart of [`sign_clob_auth_message`] for remote signers.
+pub async fn sign_clob_auth_message_async<T>(
+    signer: &T,
+    timestamp: String,
+    nonce: U256,
+    chain_id: u64,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + ?Sized,
+{
+    let hash = clob_auth_signing_hash(signer.address(), timestamp, nonce, chain_id);
     let signature = signer
-        .sign_hash_sync(&hash)
+        .sign_hash(&hash)
+        .await
         .map_err(|e| crate::error::Error::Signing(format!("Failed to sign auth message: {}", e)))?;
 
     Ok(encode_prefixed(signature.as_bytes()))
@@ -79,6 +87,112 @@ pub fn sign_order_message<T>(
 where
     T: alloy_signer::Signer + alloy_signer::SignerSync,
 {
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
+    let signature = signer
+        .sign_hash_sync(&hash)
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Signs an order using EIP-712 with an async signer
+///
+/// Async counterpart of [`sign_order_message`] for remote signers.
+pub async fn sign_order_message_async<T>(
+    signer: &T,
+    order: Order,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<String>
+where
+    T: alloy_signer::Signer + ?Sized,
+{
+    let hash = order_signing_hash(&order, chain_id, verifying_contract);
+    let signature = signer
+        .sign_hash(&hash)
+        .await
+        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+
+    Ok(encode_prefixed(signature.as_bytes()))
+}
+
+/// Recover the address that signed `order` for the exchange at `verifying_contract`
+///
+/// Rebuilds the EIP-712 order from the request fields. The result equals
+/// `order.signer` only if the order was signed for this chain and exchange
+/// with unchanged fields; anything else recovers an unrelated address.
+pub fn recover_order_signer(
+    order: &SignedOrderRequest,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<Address> {
+    let hash = order_request_hash(order, chain_id, verifying_contract)?;
+    let signature = PrimitiveSignature::from_str(&order.signature)
+        .map_err(|e| Error::Signing(format!("Invalid order signature: {}", e)))?;
+    signature
+        .recover_address_from_prehash(&hash)
+        .map_err(|e| Error::Signing(format!("Failed to recover order signer: {}", e)))
+}
+
+/// EIP-712 signing hash of a signed order request
+pub fn order_request_hash(
+    order: &SignedOrderRequest,
+    chain_id: u64,
+    verifying_contract: Address,
+) -> Result<B256> {
+    let address = |field: &str, value: &str| {
+        Address::from_str(value)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid {} address: {}", field, e)))
+    };
+    let uint = |field: &str, value: &str| {
+        U256::from_str_radix(value, 10)
+            .map_err(|e| Error::InvalidParameter(format!("Invalid {}: {}", field, e)))
+    };
+    let side = match order.side.as_str() {
+        "BUY" => Side::Buy,
+        "SELL" => Side::Sell,
+        other => return Err(Error::InvalidParameter(format!("Invalid side: {}", other))),
+    };
+
+    let order = Order {
+        salt: U256::from(order.salt),
+        maker: address("maker", &order.maker)?,
+        signer: address("signer", &order.signer)?,
+        taker: address("taker", &order.taker)?,
+        tokenId: uint("token_id", &order.token_id)?,
+        makerAmount: uint("maker_amount", &order.maker_amount)?,
+        takerAmount: uint("taker_amount", &order.taker_amount)?,
+        expiration: uint("expiration", &order.expiration)?,
+        nonce: uint("nonce", &order.nonce)?,
+        feeRateBps: uint("fee_rate_bps", &order.fee_rate_bps)?,
+        side: side.to_u8(),
+        signatureType: order.signature_type,
+    };
+    Ok(order_signing_hash(&order, chain_id, verifying_contract))
+}
+
+/// EIP-712 signing hash of a CLOB authentication message
+fn clob_auth_signing_hash(address: Address, timestamp: String, nonce: U256, chain_id: u64) -> B256 {
+    let message = "This message attests that I control the given wallet".to_owned();
+
+    let auth_struct = ClobAuth {
+        address,
+        timestamp,
+        nonce,
+        message,
+    };
+
+    let domain = eip712_domain!(
+        name: "ClobAuthDomain",
+        version: "1",
+        chain_id: chain_id,
+    );
+
+    auth_struct.eip712_signing_hash(&domain)
+}
+
+/// EIP-712 signing hash of an order
+fn order_signing_hash(order: &Order, chain_id: u64, verifying_contract: Address) -> B256 {
     let domain = eip712_domain!(
         name: "Polymarket CTF Exchange",
         version: "1",
@@ -86,10 +200,87 @@ where
         verifying_contract: verifying_contract,
     );
 
-    let hash = order.eip712_signing_hash(&domain);
-    let signature = signer
-        .sign_hash_sync(&hash)
-        .map_err(|e| crate::error::Error::Signing(format!("Failed to sign order: {}", e)))?;
+    order.eip712_signing_hash(&domain)
+}
 
-    Ok(encode_prefixed(signature.as_bytes()))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+
+    fn test_order(signer: Address) -> Order {
+        Order {
+            salt: U256::from(1u64),
+            maker: signer,
+            signer,
+            taker: Address::ZERO,
+            tokenId: U256::from(1234u64),
+            makerAmount: U256::from(5_000_000u64),
+            takerAmount: U256::from(10_000_000u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::ZERO,
+            side: 0,
+            signatureType: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_order_signature_matches_sync() {
+        let signer = PrivateKeySigner::random();
+        let exchange = Address::repeat_byte(0x42);
+
+        let sync_sig =
+            sign_order_message(&signer, test_order(signer.address()), 137, exchange).unwrap();
+        let async_sig =
+            sign_order_message_async(&signer, test_order(signer.address()), 137, exchange)
+                .await
+                .unwrap();
+        assert_eq!(sync_sig, async_sig);
+    }
+
+    #[test]
+    fn test_recover_order_signer() {
+        use crate::orders::OrderBuilder;
+        use crate::signing::ExchangeVariant;
+        use crate::types::{CreateOrderOptions, ExtraOrderArgs, OrderArgs};
+        use rust_decimal::Decimal;
+
+        let signer = PrivateKeySigner::random();
+        let signer_addr = signer.address();
+        let builder = OrderBuilder::new(signer, None, None);
+        let args = OrderArgs::new("1234", Decimal::new(5, 1), Decimal::from(10), Side::Buy);
+        let options = CreateOrderOptions::new()
+            .tick_size(Decimal::new(1, 2))
+            .neg_risk(false);
+        let order = builder
+            .create_order(137, &args, 0, &ExtraOrderArgs::default(), options)
+            .unwrap();
+
+        let standard = ExchangeVariant::Standard.exchange_address(137).unwrap();
+        let neg_risk = ExchangeVariant::NegRisk.exchange_address(137).unwrap();
+        assert_eq!(
+            recover_order_signer(&order, 137, standard).unwrap(),
+            signer_addr
+        );
+        assert_ne!(
+            recover_order_signer(&order, 137, neg_risk).unwrap(),
+            signer_addr
+        );
+        assert_ne!(
+            recover_order_signer(&order, 80002, standard).unwrap(),
+            signer_addr
+        );
+    }
+
+    #[tokio::test]
+    async fn test_async_auth_signature_matches_sync() {
+        let signer = PrivateKeySigner::random();
+
+        let sync_sig = sign_clob_auth_message(&signer, "1000".into(), U256::ZERO, 137).unwrap();
+        let async_sig = sign_clob_auth_message_async(&signer, "1000".into(), U256::ZERO, 137)
+            .await
+            .unwrap();
+        assert_eq!(sync_sig, async_sig);
+    }
 }
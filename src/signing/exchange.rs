@@ -0,0 +1,92 @@
+use crate::config::get_contract_config;
+use crate::error::{Error, Result};
+use alloy_primitives::Address;
+use std::str::FromStr;
+
+/// Exchange contract an order is signed for
+///
+/// Orders are signed with the exchange contract as the EIP-712 verifying
+/// contract, and the exchange rejects signatures made for any other
+/// contract. Standard markets trade on the CTF Exchange; negative risk
+/// markets, including augmented negative risk events
+/// ([`GammaEvent::neg_risk_augmented`](crate::types::GammaEvent::neg_risk_augmented)),
+/// trade on the Neg Risk CTF Exchange.
+///
+/// Known exchange addresses:
+///
+/// | Chain | `Standard` | `NegRisk` |
+/// |-------|------------|-----------|
+/// | Polygon (137) | `0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E` | `0xC5d563A36AE78145C45a50134d48A1215220f80a` |
+/// | Amoy (80002) | `0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40` | `0xd91E80cF2E7be2e162c6513ceD06f1dD0dA35296` |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExchangeVariant {
+    /// CTF Exchange, for standard binary markets
+    #[default]
+    Standard,
+    /// Neg Risk CTF Exchange, for negative risk (and augmented negative risk) markets
+    NegRisk,
+}
+
+impl ExchangeVariant {
+    /// Variant for a market's `neg_risk` flag
+    pub fn from_neg_risk(neg_risk: bool) -> Self {
+        if neg_risk {
+            Self::NegRisk
+        } else {
+            Self::Standard
+        }
+    }
+
+    pub fn is_neg_risk(self) -> bool {
+        matches!(self, Self::NegRisk)
+    }
+
+    /// Address of the exchange contract on `chain_id`, used as verifying contract
+    ///
+    /// Returns [`Error::Config`] for unsupported chains.
+    pub fn exchange_address(self, chain_id: u64) -> Result<Address> {
+        let config = get_contract_config(chain_id, self.is_neg_risk())?;
+        Address::from_str(&config.exchange)
+            .map_err(|e| Error::Config(format!("Invalid exchange address: {}", e)))
+    }
+}
+
+impl From<bool> for ExchangeVariant {
+    fn from(neg_risk: bool) -> Self {
+        Self::from_neg_risk(neg_risk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::chains;
+
+    #[test]
+    fn test_exchange_addresses() {
+        let address = |variant: ExchangeVariant, chain_id| {
+            variant
+                .exchange_address(chain_id)
+                .unwrap()
+                .to_checksum(None)
+        };
+        assert_eq!(
+            address(ExchangeVariant::Standard, chains::POLYGON_MAINNET),
+            "0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"
+        );
+        assert_eq!(
+            address(ExchangeVariant::NegRisk, chains::POLYGON_MAINNET),
+            "0xC5d563A36AE78145C45a50134d48A1215220f80a"
+        );
+        assert_eq!(
+            address(ExchangeVariant::Standard, chains::POLYGON_AMOY_TESTNET),
+            "0xdFE02Eb6733538f8Ea35D585af8DE5958AD99E40"
+        );
+        assert_eq!(ExchangeVariant::from(true), ExchangeVariant::NegRisk);
+        assert!(matches!(
+            ExchangeVariant::Standard.exchange_address(1),
+            Err(Error::Config(_))
+        ));
+    }
+}
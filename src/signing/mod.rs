@@ -1,5 +1,21 @@
 mod eip712;
+mod exchange;
+mod l2;
+mod proxy;
 mod signer;
+#[cfg(any(feature = "mnemonic", feature = "keystore"))]
+mod wallet;
 
-pub use eip712::{sign_clob_auth_message, sign_order_message, ClobAuth, Order};
-pub use signer::EthSigner;
+pub use eip712::{
+    order_request_hash, recover_order_signer, sign_clob_auth_message, sign_clob_auth_message_async,
+    sign_order_message, sign_order_message_async, ClobAuth, Order,
+};
+pub use exchange::ExchangeVariant;
+pub use l2::build_hmac_signature;
+pub use proxy::compute_proxy_address;
+pub(crate) use signer::SharedSigner;
+pub use signer::{AsyncEthSigner, EthSigner};
+#[cfg(feature = "keystore")]
+pub use wallet::signer_from_keystore;
+#[cfg(feature = "mnemonic")]
+pub use wallet::signer_from_mnemonic;
@@ -0,0 +1,123 @@
+use crate::config::chains;
+use crate::error::{Error, Result};
+use crate::types::SignatureType;
+use alloy_primitives::{address, b256, keccak256, Address, B256};
+
+/// Polymarket proxy wallet factory (Polygon mainnet)
+const PROXY_FACTORY: Address = address!("aB45c5A4B0c941a2F231C04C3f49182e1A254052");
+
+/// Init code hash of the Polymarket proxy wallet
+const PROXY_INIT_CODE_HASH: B256 =
+    b256!("d21df8dc65880a8606f09fe0ce3df9b8869287ab0b058be05aa9e8af6330a00b");
+
+/// Polymarket Gnosis Safe factory (Polygon mainnet)
+const SAFE_FACTORY: Address = address!("aacFeEa03eb1561C4e67d661e40682Bd20E3541b");
+
+/// Init code hash of the Polymarket Gnosis Safe proxy
+const SAFE_INIT_CODE_HASH: B256 =
+    b256!("2bce2127ff07fb632d16c8347c4ebf501f4841168bed00d9e6ef715ddb6fcecf");
+
+/// Compute the funder wallet address Polymarket deploys for an EOA
+///
+/// Polymarket deploys wallets with CREATE2, so the address is a pure function
+/// of the signer address:
+/// - [`SignatureType::PolyProxy`]: proxy wallet (email/Magic accounts), salted with
+///   `keccak256(abi.encodePacked(signer))`
+/// - [`SignatureType::PolyGnosisSafe`]: Gnosis Safe (browser wallet accounts), salted
+///   with `keccak256(abi.encode(signer))`
+/// - [`SignatureType::Eoa`]: the signer address itself
+///
+/// Only the Polygon mainnet factories are known, so proxy and Safe wallets
+/// can't be derived on other chains.
+///
+/// # Example
+/// ```
+/// use polymarket_rs::signing::compute_proxy_address;
+/// use polymarket_rs::{Address, SignatureType};
+///
+/// let eoa = Address::repeat_byte(0x11);
+/// let proxy = compute_proxy_address(eoa, SignatureType::PolyProxy, 137).unwrap();
+/// assert_ne!(proxy, eoa);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] for proxy and Safe wallets on chains other than
+/// Polygon mainnet.
+pub fn compute_proxy_address(
+    signer_address: Address,
+    signature_type: SignatureType,
+    chain_id: u64,
+) -> Result<Address> {
+    if signature_type != SignatureType::Eoa && chain_id != chains::POLYGON_MAINNET {
+        return Err(Error::Config(format!(
+            "No known {:?} wallet factory on chain {}; pass the funder address explicitly",
+            signature_type, chain_id
+        )));
+    }
+    Ok(match signature_type {
+        SignatureType::Eoa => signer_address,
+        SignatureType::PolyProxy => {
+            let salt = keccak256(signer_address.as_slice());
+            PROXY_FACTORY.create2(salt, PROXY_INIT_CODE_HASH)
+        }
+        SignatureType::PolyGnosisSafe => {
+            let salt = keccak256(signer_address.into_word().as_slice());
+            SAFE_FACTORY.create2(salt, SAFE_INIT_CODE_HASH)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNER: Address = address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+    #[test]
+    fn test_eoa_is_identity() {
+        for chain_id in [chains::POLYGON_MAINNET, chains::POLYGON_AMOY_TESTNET] {
+            assert_eq!(
+                compute_proxy_address(SIGNER, SignatureType::Eoa, chain_id).unwrap(),
+                SIGNER
+            );
+        }
+    }
+
+    // Known wallets for the signer above, from the test vectors of
+    // Polymarket's own Rust CLOB client (`derive_proxy_wallet` and
+    // `derive_safe_wallet`)
+
+    #[test]
+    fn test_proxy_address() {
+        assert_eq!(
+            compute_proxy_address(SIGNER, SignatureType::PolyProxy, 137).unwrap(),
+            address!("365f0CA36Ae1f641E02fE3B7743673da42A13A70")
+        );
+    }
+
+    #[test]
+    fn test_safe_address() {
+        assert_eq!(
+            compute_proxy_address(SIGNER, SignatureType::PolyGnosisSafe, 137).unwrap(),
+            address!("d93B25cb943D14d0d34FBaF01Fc93a0f8b5F6E47")
+        );
+    }
+
+    #[test]
+    fn test_wallet_types_differ() {
+        let proxy = compute_proxy_address(SIGNER, SignatureType::PolyProxy, 137).unwrap();
+        let safe = compute_proxy_address(SIGNER, SignatureType::PolyGnosisSafe, 137).unwrap();
+        assert_ne!(proxy, safe);
+        assert_ne!(proxy, SIGNER);
+        assert_ne!(safe, SIGNER);
+    }
+
+    #[test]
+    fn test_unknown_factory_chain() {
+        for sig_type in [SignatureType::PolyProxy, SignatureType::PolyGnosisSafe] {
+            let result = compute_proxy_address(SIGNER, sig_type, chains::POLYGON_AMOY_TESTNET);
+            assert!(matches!(result, Err(Error::Config(_))));
+        }
+    }
+}
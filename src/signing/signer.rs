@@ -1,4 +1,7 @@
-use alloy_signer::{Signer, SignerSync};
+use alloy_primitives::{Address, ChainId, B256};
+use alloy_signer::{Signature, Signer, SignerSync};
+use async_trait::async_trait;
+use std::sync::Arc;
 
 /// Trait for Ethereum signers used in Polymarket operations
 ///
@@ -8,3 +11,58 @@ pub trait EthSigner: Signer + SignerSync + Send + Sync {}
 
 // Blanket implementation for any type that meets the requirements
 impl<T: Signer + SignerSync + Send + Sync> EthSigner for T {}
+
+/// Trait for Ethereum signers that can only sign asynchronously
+///
+/// Remote signers (AWS KMS, Fireblocks, hardware wallets) implement
+/// [`Signer`] but not [`SignerSync`]. They can be used with the async
+/// signing functions such as [`sign_order_message_async`](super::sign_order_message_async).
+/// Every [`EthSigner`] is also an `AsyncEthSigner`.
+pub trait AsyncEthSigner: Signer + Send + Sync {}
+
+// Blanket implementation for any type that meets the requirements
+impl<T: Signer + Send + Sync> AsyncEthSigner for T {}
+
+/// Signer shared by several owners, e.g. a client and its order builder
+///
+/// Cloning shares the underlying signer. [`Signer::set_chain_id`] only takes
+/// effect while the signer isn't shared.
+#[derive(Clone)]
+pub(crate) struct SharedSigner(Arc<dyn EthSigner>);
+
+impl SharedSigner {
+    pub(crate) fn new(signer: Box<dyn EthSigner>) -> Self {
+        Self(Arc::from(signer))
+    }
+}
+
+#[async_trait]
+impl Signer for SharedSigner {
+    async fn sign_hash(&self, hash: &B256) -> alloy_signer::Result<Signature> {
+        self.0.sign_hash(hash).await
+    }
+
+    fn address(&self) -> Address {
+        self.0.address()
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.0.chain_id()
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        if let Some(signer) = Arc::get_mut(&mut self.0) {
+            signer.set_chain_id(chain_id);
+        }
+    }
+}
+
+impl SignerSync for SharedSigner {
+    fn sign_hash_sync(&self, hash: &B256) -> alloy_signer::Result<Signature> {
+        self.0.sign_hash_sync(hash)
+    }
+
+    fn chain_id_sync(&self) -> Option<ChainId> {
+        self.0.chain_id_sync()
+    }
+}
@@ -0,0 +1,93 @@
+//! Loading signers from a mnemonic phrase or an encrypted keystore
+
+#[cfg(feature = "keystore")]
+use std::path::Path;
+
+use alloy_signer_local::PrivateKeySigner;
+#[cfg(feature = "mnemonic")]
+use alloy_signer_local::{coins_bip39::English, MnemonicBuilder};
+
+use crate::error::{Error, Result};
+
+/// Derive a signer from a BIP-39 English mnemonic phrase
+///
+/// Uses the standard Ethereum derivation path `m/44'/60'/0'/0/{index}`, so
+/// index 0 is the first account shown by most wallets.
+///
+/// Requires the `mnemonic` feature.
+#[cfg(feature = "mnemonic")]
+pub fn signer_from_mnemonic(phrase: &str, index: u32) -> Result<PrivateKeySigner> {
+    MnemonicBuilder::<English>::default()
+        .phrase(phrase.trim())
+        .index(index)
+        .and_then(|builder| builder.build())
+        .map_err(|e| Error::Config(format!("Invalid mnemonic: {}", e)))
+}
+
+/// Decrypt a signer from an encrypted JSON keystore (Web3 Secret Storage) file
+///
+/// Requires the `keystore` feature.
+#[cfg(feature = "keystore")]
+pub fn signer_from_keystore(
+    path: impl AsRef<Path>,
+    password: impl AsRef<[u8]>,
+) -> Result<PrivateKeySigner> {
+    let path = path.as_ref();
+    PrivateKeySigner::decrypt_keystore(path, password).map_err(|e| {
+        Error::Config(format!(
+            "Failed to decrypt keystore {}: {}",
+            path.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "mnemonic")]
+    #[test]
+    fn test_signer_from_mnemonic() {
+        let phrase = "test test test test test test test test test test test junk";
+        let signer = signer_from_mnemonic(phrase, 0).unwrap();
+        assert_eq!(
+            signer.address().to_checksum(None),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+        let signer = signer_from_mnemonic(phrase, 1).unwrap();
+        assert_eq!(
+            signer.address().to_checksum(None),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+        );
+        assert!(matches!(
+            signer_from_mnemonic("not a mnemonic", 0),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[cfg(feature = "keystore")]
+    #[test]
+    fn test_signer_from_keystore() {
+        let dir =
+            std::env::temp_dir().join(format!("polymarket-rs-keystore-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let key = [0x11u8; 32];
+        let (expected, _) = PrivateKeySigner::encrypt_keystore(
+            &dir,
+            &mut rand::thread_rng(),
+            key,
+            "password",
+            Some("key.json"),
+        )
+        .unwrap();
+
+        let signer = signer_from_keystore(dir.join("key.json"), "password").unwrap();
+        assert_eq!(signer.address(), expected.address());
+        assert!(matches!(
+            signer_from_keystore(dir.join("key.json"), "wrong"),
+            Err(Error::Config(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
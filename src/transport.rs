@@ -0,0 +1,232 @@
+//! Pluggable HTTP transport.
+//!
+//! All REST clients send requests through a [`Transport`]. The default is
+//! [`ReqwestTransport`]; tests can swap in a [`MockTransport`] (behind the
+//! `test-util` feature) to return canned JSON without network access:
+//!
+//! ```
+//! # #[cfg(feature = "test-util")]
+//! # async fn example() -> polymarket_rs::Result<()> {
+//! use polymarket_rs::transport::MockTransport;
+//! use polymarket_rs::{ClobClient, TokenId};
+//!
+//! let mock = MockTransport::new().on("GET", "/midpoint", 200, r#"{"mid": "0.5"}"#);
+//! let client = ClobClient::new("https://clob.polymarket.com").with_transport(mock.clone());
+//!
+//! let midpoint = client.get_midpoint(&TokenId::new("123")).await?;
+//! assert_eq!(mock.requests().len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use futures_util::future::BoxFuture;
+use reqwest::{Client, Method};
+
+use crate::error::Result;
+
+/// HTTP request handed to a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Full request URL, including query parameters
+    pub url: String,
+    /// Request headers
+    pub headers: Vec<(String, String)>,
+    /// JSON-encoded request body
+    pub body: Option<String>,
+}
+
+/// Raw HTTP response returned by a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response body
+    pub body: String,
+}
+
+/// Sends HTTP requests on behalf of the REST clients
+///
+/// Implementations only move bytes; status handling and JSON decoding are
+/// done by the clients.
+pub trait Transport: Send + Sync {
+    /// Send a request and return the raw response
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+}
+
+/// Default [`Transport`] backed by `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport with a default `reqwest::Client`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a transport from a preconfigured `reqwest::Client`
+    pub fn with_client(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let mut builder = self.client.request(request.method, &request.url);
+            for (key, value) in request.headers {
+                builder = builder.header(key, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let body = response.text().await?;
+            Ok(HttpResponse { status, body })
+        })
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::MockTransport;
+
+#[cfg(any(test, feature = "test-util"))]
+mod mock {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct MockState {
+        routes: Vec<(Method, String, HttpResponse)>,
+        requests: Vec<HttpRequest>,
+    }
+
+    /// In-memory [`Transport`] returning canned responses
+    ///
+    /// Responses are registered per method and URL path. A route without a
+    /// query string matches any query; a route with one (e.g.
+    /// `/data/orders?next_cursor=abc`) only matches that exact query and takes
+    /// priority. Unmatched requests get a 404 response. Clones
+    /// share state, so a test can keep a handle to inspect recorded requests
+    /// after moving a clone into a client.
+    #[derive(Clone, Default)]
+    pub struct MockTransport {
+        state: Arc<Mutex<MockState>>,
+    }
+
+    impl MockTransport {
+        /// Create a mock with no registered responses
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Register a response for requests matching `method` and `path`
+        ///
+        /// Later registrations for the same route take precedence.
+        pub fn on(self, method: &str, path: &str, status: u16, body: impl Into<String>) -> Self {
+            let method = Method::from_bytes(method.as_bytes()).expect("invalid HTTP method");
+            let response = HttpResponse {
+                status,
+                body: body.into(),
+            };
+            self.state
+                .lock()
+                .unwrap()
+                .routes
+                .push((method, path.to_string(), response));
+            self
+        }
+
+        /// All requests sent through this transport, in order
+        pub fn requests(&self) -> Vec<HttpRequest> {
+            self.state.lock().unwrap().requests.clone()
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+            let url = reqwest::Url::parse(&request.url).ok();
+            let path = url
+                .as_ref()
+                .map(|url| url.path().to_string())
+                .unwrap_or_default();
+            let path_and_query = match url.as_ref().and_then(|url| url.query()) {
+                Some(query) => format!("{}?{}", path, query),
+                None => path.clone(),
+            };
+
+            let mut state = self.state.lock().unwrap();
+            let find = |target: &str| {
+                state
+                    .routes
+                    .iter()
+                    .rev()
+                    .find(|(method, route, _)| *method == request.method && route == target)
+                    .map(|(_, _, response)| response.clone())
+            };
+            let response = find(&path_and_query)
+                .or_else(|| find(&path))
+                .unwrap_or_else(|| HttpResponse {
+                    status: 404,
+                    body: format!("no mock response for {} {}", request.method, path),
+                });
+            state.requests.push(request);
+
+            Box::pin(async move { Ok(response) })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::http::HttpClient;
+
+    #[tokio::test]
+    async fn test_mock_transport_returns_canned_response() {
+        let mock = MockTransport::new().on("GET", "/time", 200, "1700000000");
+        let client = HttpClient::new("https://example.com").with_transport(mock.clone());
+
+        let time: u64 = client.get("/time?x=1", None).await.unwrap();
+        assert_eq!(time, 1700000000);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, Method::GET);
+        assert_eq!(requests[0].url, "https://example.com/time?x=1");
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_records_body_and_headers() {
+        let mock = MockTransport::new().on("POST", "/order", 200, "{}");
+        let client = HttpClient::new("https://example.com").with_transport(mock.clone());
+
+        let headers = std::collections::HashMap::from([("POLY_API_KEY", "key".to_string())]);
+        let _: serde_json::Value = client
+            .post("/order", &serde_json::json!({"a": 1}), Some(headers))
+            .await
+            .unwrap();
+
+        let request = &mock.requests()[0];
+        assert_eq!(request.body.as_deref(), Some(r#"{"a":1}"#));
+        assert!(request
+            .headers
+            .contains(&("POLY_API_KEY".to_string(), "key".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_unmatched_is_api_error() {
+        let client = HttpClient::new("https://example.com").with_transport(MockTransport::new());
+
+        let result: Result<serde_json::Value> = client.get("/missing", None).await;
+        assert!(matches!(result, Err(Error::Api { status: 404, .. })));
+    }
+}
@@ -1,4 +1,7 @@
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
 
 /// API credentials for L2 authentication
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -23,7 +26,101 @@ impl ApiCreds {
 #[derive(Debug, Deserialize)]
 pub struct ApiKeysResponse {
     #[serde(rename = "apiKeys")]
-    pub api_keys: Vec<String>,
+    pub api_keys: Vec<ApiKeyInfo>,
+}
+
+impl ApiKeysResponse {
+    /// The API keys as plain strings
+    pub fn keys(&self) -> Vec<&str> {
+        self.api_keys.iter().map(|info| info.key.as_str()).collect()
+    }
+
+    /// Find the entry for `key`
+    pub fn find(&self, key: &str) -> Option<&ApiKeyInfo> {
+        self.api_keys.iter().find(|info| info.key == key)
+    }
+
+    /// Whether `key` belongs to the account
+    pub fn contains(&self, key: &str) -> bool {
+        self.find(key).is_some()
+    }
+}
+
+/// API key of the account
+///
+/// The endpoint lists keys either as plain strings or as objects with
+/// metadata; both forms are accepted, with metadata left as `None` when it
+/// isn't returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyInfo {
+    pub key: String,
+    pub created_at: Option<DateTime<Utc>>,
+    /// Whether the key can be used, if reported
+    pub active: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for ApiKeyInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Detailed {
+            #[serde(alias = "apiKey", alias = "api_key", alias = "id")]
+            key: String,
+            #[serde(
+                default,
+                alias = "createdAt",
+                deserialize_with = "super::serde_helpers::deserialize_optional_datetime"
+            )]
+            created_at: Option<DateTime<Utc>>,
+            #[serde(default)]
+            active: Option<bool>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Key(String),
+            Detailed(Detailed),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Key(key) => ApiKeyInfo {
+                key,
+                created_at: None,
+                active: None,
+            },
+            Repr::Detailed(detailed) => ApiKeyInfo {
+                key: detailed.key,
+                created_at: detailed.created_at,
+                active: detailed.active,
+            },
+        })
+    }
+}
+
+/// Balance and allowance of a single asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceAllowance {
+    /// Balance in whole units
+    ///
+    /// The API reports base units (USDC and outcome tokens use 6 decimals);
+    /// they are converted on deserialization, so `"2500000"` becomes `2.5`.
+    /// The conversion always uses USDC's 6 decimals, also for clients
+    /// configured with
+    /// [`with_collateral_decimals`](crate::OrderBuilder::with_collateral_decimals);
+    /// for other collateral, convert back with
+    /// [`decimal_to_token_u64`](crate::orders::decimal_to_token_u64) and
+    /// [`USDC_DECIMALS`](crate::orders::USDC_DECIMALS), then divide by the
+    /// collateral's decimals.
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_token_amount")]
+    pub balance: Decimal,
+    /// Allowance per spender contract in base units
+    ///
+    /// Kept as strings since unlimited approvals exceed `Decimal` range.
+    #[serde(default)]
+    pub allowances: HashMap<String, String>,
 }
 
 /// Balance and allowance query parameters
@@ -76,3 +173,39 @@ impl BalanceAllowanceParams {
         params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_keys_response() {
+        let response: ApiKeysResponse = serde_json::from_str(
+            r#"{"apiKeys": [
+                "6f2b5c4e-1111-4a4a-9999-000000000001",
+                {"apiKey": "6f2b5c4e-1111-4a4a-9999-000000000002",
+                 "createdAt": "2024-05-01T12:00:00Z", "active": false}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response.keys(),
+            [
+                "6f2b5c4e-1111-4a4a-9999-000000000001",
+                "6f2b5c4e-1111-4a4a-9999-000000000002"
+            ]
+        );
+        assert_eq!(response.api_keys[0].created_at, None);
+
+        let info = response
+            .find("6f2b5c4e-1111-4a4a-9999-000000000002")
+            .unwrap();
+        assert_eq!(info.active, Some(false));
+        assert_eq!(
+            info.created_at.unwrap().to_rfc3339(),
+            "2024-05-01T12:00:00+00:00"
+        );
+        assert!(!response.contains("missing"));
+    }
+}
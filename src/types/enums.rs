@@ -103,13 +103,71 @@ pub enum MarketStatus {
 }
 
 /// Order status
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Statuses not known to this client deserialize into [`OrderStatus::Other`]
+/// instead of failing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderStatus {
     Live,
     Matched,
+    #[serde(alias = "CANCELLED")]
     Canceled,
     Expired,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl OrderStatus {
+    /// Status string as sent by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            OrderStatus::Live => "LIVE",
+            OrderStatus::Matched => "MATCHED",
+            OrderStatus::Canceled => "CANCELED",
+            OrderStatus::Expired => "EXPIRED",
+            OrderStatus::Other(status) => status,
+        }
+    }
+}
+
+impl std::fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Placement status of a posted order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderPlacementStatus {
+    /// Order is resting on the book
+    Live,
+    /// Order was matched on placement
+    Matched,
+    /// Order is marketable but subject to a matching delay
+    Delayed,
+    /// Order is marketable but could not be matched (delay failed)
+    Unmatched,
+    /// The exchange rejected the order
+    Failed,
+    /// Status string not recognized by this client
+    Unknown(String),
+}
+
+impl OrderPlacementStatus {
+    /// Parse a placement status from the `status` and `success` response fields
+    pub fn from_response(status: &str, success: bool) -> Self {
+        if !success {
+            return OrderPlacementStatus::Failed;
+        }
+        match status.to_ascii_lowercase().as_str() {
+            "live" => OrderPlacementStatus::Live,
+            "matched" => OrderPlacementStatus::Matched,
+            "delayed" => OrderPlacementStatus::Delayed,
+            "unmatched" => OrderPlacementStatus::Unmatched,
+            _ => OrderPlacementStatus::Unknown(status.to_string()),
+        }
+    }
 }
 
 /// Notification type
@@ -136,3 +194,25 @@ pub enum ActivityType {
     Conversion,
     Redeem,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_status_serde() {
+        let status: OrderStatus = serde_json::from_str(r#""LIVE""#).unwrap();
+        assert_eq!(status, OrderStatus::Live);
+        let status: OrderStatus = serde_json::from_str(r#""CANCELLED""#).unwrap();
+        assert_eq!(status, OrderStatus::Canceled);
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""CANCELED""#);
+    }
+
+    #[test]
+    fn test_order_status_unknown() {
+        let status: OrderStatus = serde_json::from_str(r#""DELAYED""#).unwrap();
+        assert_eq!(status, OrderStatus::Other("DELAYED".to_string()));
+        assert_eq!(status.as_str(), "DELAYED");
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""DELAYED""#);
+    }
+}
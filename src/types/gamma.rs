@@ -13,6 +13,11 @@ pub struct GammaMarket {
     pub outcome_prices: Option<String>, // JSON string
     pub clob_token_ids: Option<String>, // JSON string
     pub condition_id: String,
+    /// Label of this outcome within a grouped (neg risk) event, e.g. a candidate name
+    pub group_item_title: Option<String>,
+    /// Shared id of the neg risk market this market belongs to
+    #[serde(rename = "negRiskMarketID")]
+    pub neg_risk_market_id: Option<String>,
 
     // Status flags
     #[serde(default)]
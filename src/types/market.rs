@@ -1,6 +1,10 @@
 use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use super::primitives::{ConditionId, TokenId};
+use crate::request::Cursor;
 
 /// Full market information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +27,18 @@ pub struct Market {
     pub minimum_order_size: Decimal,
     #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub minimum_tick_size: Decimal,
+    /// Maker base fee in basis points (0 if not returned)
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
+    pub maker_base_fee: Decimal,
+    /// Taker base fee in basis points (0 if not returned)
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_decimal"
+    )]
+    pub taker_base_fee: Decimal,
     pub description: String,
     pub category: Option<String>,
     #[serde(deserialize_with = "super::serde_helpers::deserialize_optional_datetime")]
@@ -48,6 +64,38 @@ impl Market {
         }
         true
     }
+
+    /// Token ID for an outcome name (e.g. "Yes"), compared case-insensitively
+    pub fn token_id_for_outcome(&self, outcome: &str) -> Option<&TokenId> {
+        self.tokens
+            .iter()
+            .find(|t| t.outcome.eq_ignore_ascii_case(outcome.trim()))
+            .map(|t| &t.token_id)
+    }
+
+    /// Outcome name for one of this market's token IDs
+    pub fn outcome_for_token(&self, token_id: &TokenId) -> Option<&str> {
+        self.tokens
+            .iter()
+            .find(|t| &t.token_id == token_id)
+            .map(|t| t.outcome.as_str())
+    }
+
+    /// The other outcome's token, e.g. the "No" token for the "Yes" token
+    ///
+    /// CLOB markets always have exactly two outcomes; multi-outcome events
+    /// are groups of binary (neg risk) markets, each with its own pair.
+    /// Returns None if `token_id` isn't one of this market's tokens.
+    pub fn complement_token(&self, token_id: &TokenId) -> Option<&TokenId> {
+        let [first, second] = &self.tokens;
+        if &first.token_id == token_id {
+            Some(&second.token_id)
+        } else if &second.token_id == token_id {
+            Some(&first.token_id)
+        } else {
+            None
+        }
+    }
 }
 
 /// Simplified market information
@@ -65,7 +113,7 @@ pub struct SimplifiedMarket {
 /// Token within a market
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
-    pub token_id: String,
+    pub token_id: TokenId,
     pub outcome: String,
 }
 
@@ -95,6 +143,44 @@ pub struct MarketsResponse {
     pub data: Vec<Market>,
 }
 
+impl MarketsResponse {
+    /// Cursor of the next page, or None on the last page
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::next(self.next_cursor.as_deref())
+    }
+
+    /// Number of markets on this page
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Market> {
+        self.data.iter()
+    }
+}
+
+impl IntoIterator for MarketsResponse {
+    type Item = Market;
+    type IntoIter = std::vec::IntoIter<Market>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MarketsResponse {
+    type Item = &'a Market;
+    type IntoIter = std::slice::Iter<'a, Market>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 /// Paginated simplified markets response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SimplifiedMarketsResponse {
@@ -104,17 +190,70 @@ pub struct SimplifiedMarketsResponse {
     pub data: Vec<SimplifiedMarket>,
 }
 
+impl SimplifiedMarketsResponse {
+    /// Cursor of the next page, or None on the last page
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::next(self.next_cursor.as_deref())
+    }
+}
+
+/// Paginated condition and token IDs of markets
+///
+/// Deserialized from the simplified markets endpoint, skipping everything
+/// but the IDs.
+#[derive(Debug, Deserialize)]
+pub struct MarketIdsResponse {
+    pub next_cursor: Option<String>,
+    /// Condition ID of each market with the IDs of its outcome tokens
+    #[serde(deserialize_with = "deserialize_market_ids")]
+    pub data: Vec<(ConditionId, Vec<TokenId>)>,
+}
+
+impl MarketIdsResponse {
+    /// Cursor of the next page, or None on the last page
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::next(self.next_cursor.as_deref())
+    }
+}
+
+fn deserialize_market_ids<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<(ConditionId, Vec<TokenId>)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct TokenIds {
+        token_id: TokenId,
+    }
+
+    #[derive(Deserialize)]
+    struct MarketIds {
+        condition_id: ConditionId,
+        tokens: Vec<TokenIds>,
+    }
+
+    let markets = Vec::<MarketIds>::deserialize(deserializer)?;
+    Ok(markets
+        .into_iter()
+        .map(|m| {
+            let tokens = m.tokens.into_iter().map(|t| t.token_id).collect();
+            (m.condition_id, tokens)
+        })
+        .collect())
+}
+
 /// Midpoint price response
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MidpointResponse {
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub mid: Decimal,
 }
 
 /// Price response
 #[derive(Debug, Deserialize)]
 pub struct PriceResponse {
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub price: Decimal,
 }
 
@@ -139,10 +278,34 @@ pub struct PriceHistory {
 /// Spread response
 #[derive(Debug, Deserialize)]
 pub struct SpreadResponse {
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub spread: Decimal,
 }
 
+impl SpreadResponse {
+    /// Spread between best ask and best bid
+    pub fn spread_decimal(&self) -> Decimal {
+        self.spread
+    }
+
+    /// Spread in basis points of the price range, e.g. 100 for a spread of 0.01
+    ///
+    /// Prices run from 0 to 1, so one basis point is a price difference of
+    /// 0.0001.
+    pub fn spread_bps(&self) -> Decimal {
+        self.spread * Decimal::from(10_000)
+    }
+}
+
+/// Quoting summary for one token, see [`ClobClient::get_book_stats`](crate::ClobClient::get_book_stats)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BookStats {
+    pub midpoint: Option<Decimal>,
+    pub spread: Option<Decimal>,
+    pub best_bid: Option<Decimal>,
+    pub best_ask: Option<Decimal>,
+}
+
 /// Tick size response
 #[derive(Debug, Deserialize)]
 pub struct TickSizeResponse {
@@ -156,6 +319,31 @@ pub struct NegRiskResponse {
     pub neg_risk: bool,
 }
 
+/// Result of [`ClobClient::health_check`](crate::ClobClient::health_check)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    /// Whether the server answered the health endpoint successfully
+    pub reachable: bool,
+    /// Round-trip time of the health request, including failed ones
+    pub latency: Duration,
+    /// Server Unix time in seconds, or None if it couldn't be fetched
+    pub server_time: Option<u64>,
+    /// Why the server is unreachable
+    pub error: Option<String>,
+}
+
+impl HealthStatus {
+    /// Server time minus local time in seconds, given the local time at
+    /// which the check ran
+    ///
+    /// Feed the result to [`OffsetTimeSource`](crate::orders::OffsetTimeSource)
+    /// to correct for clock skew.
+    pub fn clock_offset(&self, local_secs: u64) -> Option<i64> {
+        self.server_time
+            .map(|server| server as i64 - local_secs as i64)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -166,11 +354,11 @@ mod tests {
             condition_id: "test".to_string(),
             tokens: [
                 Token {
-                    token_id: "token1".to_string(),
+                    token_id: TokenId::new("token1"),
                     outcome: "Yes".to_string(),
                 },
                 Token {
-                    token_id: "token2".to_string(),
+                    token_id: TokenId::new("token2"),
                     outcome: "No".to_string(),
                 },
             ],
@@ -195,6 +383,8 @@ mod tests {
             question: "Test question?".to_string(),
             minimum_order_size: Decimal::ZERO,
             minimum_tick_size: Decimal::ZERO,
+            maker_base_fee: Decimal::ZERO,
+            taker_base_fee: Decimal::ZERO,
             description: "Test".to_string(),
             category: None,
             end_date_iso,
@@ -208,6 +398,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_markets_response_iteration() {
+        let response = MarketsResponse {
+            limit: 2,
+            count: 2,
+            next_cursor: Some("LTE=".to_string()),
+            data: vec![create_test_market(None), create_test_market(None)],
+        };
+        assert_eq!(response.len(), 2);
+        assert!(!response.is_empty());
+        assert_eq!(response.next_cursor(), None);
+
+        let slugs: Vec<&str> = (&response)
+            .into_iter()
+            .map(|m| m.market_slug.as_str())
+            .collect();
+        assert_eq!(slugs, ["test-market", "test-market"]);
+
+        let mut count = 0;
+        for market in response {
+            assert_eq!(market.condition_id, "test");
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+
     #[test]
     fn test_ends_within_near_future() {
         // Market ending in 1 hour should end within 2 hours
@@ -243,4 +459,112 @@ mod tests {
         assert!(market.ends_within(TimeDelta::hours(1)));
         assert!(market.ends_within(TimeDelta::days(7)));
     }
+
+    #[test]
+    fn test_create_order_options_from_market() {
+        let mut market = create_test_market(None);
+        market.minimum_tick_size = Decimal::new(1, 2);
+        market.neg_risk = true;
+
+        market.minimum_order_size = Decimal::from(5);
+
+        let options = crate::types::CreateOrderOptions::from_market(&market);
+        assert_eq!(options.tick_size, Some(Decimal::new(1, 2)));
+        assert_eq!(options.neg_risk, Some(true));
+        assert_eq!(options.min_order_size, Some(Decimal::from(5)));
+
+        // Explicit overrides win over market values
+        let options =
+            crate::types::CreateOrderOptions::from_market(&market).tick_size(Decimal::new(1, 3));
+        assert_eq!(options.tick_size, Some(Decimal::new(1, 3)));
+        assert_eq!(options.neg_risk, Some(true));
+    }
+
+    #[test]
+    fn test_outcome_token_mapping() {
+        let market = create_test_market(None);
+
+        assert_eq!(
+            market.token_id_for_outcome("yes"),
+            Some(&TokenId::new("token1"))
+        );
+        assert_eq!(
+            market.token_id_for_outcome("NO"),
+            Some(&TokenId::new("token2"))
+        );
+        assert_eq!(market.token_id_for_outcome("Maybe"), None);
+
+        assert_eq!(
+            market.outcome_for_token(&TokenId::new("token2")),
+            Some("No")
+        );
+        assert_eq!(market.outcome_for_token(&TokenId::new("token3")), None);
+
+        assert_eq!(
+            market.complement_token(&TokenId::new("token1")),
+            Some(&TokenId::new("token2"))
+        );
+        assert_eq!(
+            market.complement_token(&TokenId::new("token2")),
+            Some(&TokenId::new("token1"))
+        );
+        assert_eq!(market.complement_token(&TokenId::new("token3")), None);
+    }
+
+    #[test]
+    fn test_market_fixture_minimums_and_fees() {
+        // Shape of a `GET /markets/{condition_id}` response
+        let market: Market = serde_json::from_str(
+            r#"{
+                "enable_order_book": true,
+                "active": true,
+                "closed": false,
+                "archived": false,
+                "accepting_orders": true,
+                "accepting_order_timestamp": "2024-01-05T17:42:11Z",
+                "minimum_order_size": 5,
+                "minimum_tick_size": 0.01,
+                "condition_id": "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917",
+                "question_id": "0xe3b1bc389210504ebcb9cffe4b0ed06ccac50561e0f24abb6379984cec030f00",
+                "question": "Will it rain tomorrow?",
+                "description": "Resolves Yes if it rains.",
+                "market_slug": "will-it-rain-tomorrow",
+                "end_date_iso": "2030-01-01T00:00:00Z",
+                "game_start_time": null,
+                "seconds_delay": 0,
+                "fpmm": "",
+                "maker_base_fee": 0,
+                "taker_base_fee": 200,
+                "notifications_enabled": true,
+                "neg_risk": false,
+                "neg_risk_market_id": "",
+                "neg_risk_request_id": "",
+                "icon": "https://example.com/icon.png",
+                "image": "https://example.com/image.png",
+                "rewards": {"rates": null, "min_size": 0, "max_spread": 0},
+                "is_50_50_outcome": false,
+                "tokens": [
+                    {"token_id": "1111", "outcome": "Yes", "price": 0.5, "winner": false},
+                    {"token_id": "2222", "outcome": "No", "price": 0.5, "winner": false}
+                ],
+                "tags": ["Weather"]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(market.minimum_order_size, Decimal::from(5));
+        assert_eq!(market.minimum_tick_size, Decimal::new(1, 2));
+        assert_eq!(market.maker_base_fee, Decimal::ZERO);
+        assert_eq!(market.taker_base_fee, Decimal::from(200));
+
+        let extras = crate::types::ExtraOrderArgs::from_market(&market);
+        assert_eq!(extras.fee_rate_bps, Some(200));
+    }
+
+    #[test]
+    fn test_spread_helpers() {
+        let spread: SpreadResponse = serde_json::from_str(r#"{"spread": "0.015"}"#).unwrap();
+        assert_eq!(spread.spread_decimal(), Decimal::new(15, 3));
+        assert_eq!(spread.spread_bps(), Decimal::from(150));
+    }
 }
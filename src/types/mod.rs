@@ -1,8 +1,19 @@
+//! Request and response types for the Polymarket APIs.
+//!
+//! # Forward compatibility
+//!
+//! Response types never use `#[serde(deny_unknown_fields)]`: fields the
+//! server adds are ignored during deserialization, so a new server field
+//! cannot break an existing client. Fields that are not always present are
+//! `Option` or `#[serde(default)]`.
+
 mod auth;
 mod enums;
 mod gamma;
 mod market;
+mod neg_risk;
 mod order;
+mod portfolio;
 mod primitives;
 mod serde_helpers;
 mod trade;
@@ -13,9 +24,64 @@ pub use auth::*;
 pub use enums::*;
 pub use gamma::*;
 pub use market::*;
+pub use neg_risk::*;
 pub use order::*;
+pub use portfolio::*;
 pub use primitives::*;
 pub use trade::*;
 pub use websocket::*;
 
 // Keep serde_helpers internal but accessible within crate
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+
+    /// Deserialize `json` after adding an unknown field to the top-level object
+    fn with_extra_field<T: DeserializeOwned>(json: &str) -> T {
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        value.as_object_mut().unwrap().insert(
+            "someNewServerField".into(),
+            serde_json::json!({"nested": [1, 2]}),
+        );
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_responses_tolerate_unknown_fields() {
+        with_extra_field::<OrderBookSummary>(
+            r#"{"market": "0x1", "asset_id": "1", "hash": "h", "timestamp": "1",
+                "bids": [{"price": "0.5", "size": "10", "extra": 1}], "asks": []}"#,
+        );
+        with_extra_field::<PostOrderResponse>(
+            r#"{"errorMsg": "", "orderID": "0x1", "status": "live", "success": true}"#,
+        );
+        with_extra_field::<CancelOrdersResponse>(r#"{"canceled": [], "not_canceled": {}}"#);
+        with_extra_field::<MidpointResponse>(r#"{"mid": "0.5"}"#);
+        with_extra_field::<PriceResponse>(r#"{"price": "0.5"}"#);
+        with_extra_field::<SpreadResponse>(r#"{"spread": "0.01"}"#);
+        with_extra_field::<TickSizeResponse>(r#"{"minimum_tick_size": 0.01}"#);
+        with_extra_field::<NegRiskResponse>(r#"{"neg_risk": false}"#);
+        with_extra_field::<ApiKeysResponse>(r#"{"apiKeys": []}"#);
+        with_extra_field::<ApiCreds>(r#"{"apiKey": "k", "secret": "s", "passphrase": "p"}"#);
+        with_extra_field::<PositionValue>(r#"{"user": "0x1", "value": 1.5}"#);
+        with_extra_field::<GammaTag>(r#"{"id": "1", "label": "Politics", "slug": "politics"}"#);
+        with_extra_field::<GammaCategory>(r#"{"id": "1", "label": "Sports", "slug": "sports"}"#);
+    }
+
+    #[test]
+    fn test_ws_events_tolerate_unknown_fields() {
+        let event = with_extra_field::<WsEvent>(
+            r#"{"event_type": "book", "market": "0x1", "asset_id": "1", "timestamp": "1",
+                "hash": "h", "bids": [], "asks": []}"#,
+        );
+        assert!(matches!(event, WsEvent::Book(_)));
+
+        let event = with_extra_field::<WsEvent>(
+            r#"{"event_type": "price_change", "market": "0x1", "price_changes": [
+                {"asset_id": "1", "side": "BUY", "price": "0.5", "size": "10", "extra": true}]}"#,
+        );
+        assert!(matches!(event, WsEvent::PriceChange(_)));
+    }
+}
@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use super::gamma::GammaEvent;
+use super::market::Market;
+
+/// One outcome of a neg risk market
+///
+/// Each outcome is its own binary market (condition) with a Yes and a No token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegRiskOutcome {
+    /// Condition ID of the outcome's binary market
+    pub condition_id: String,
+    /// Question of the outcome's binary market
+    pub question: String,
+    /// Token paying out if this outcome happens
+    pub yes_token_id: String,
+    /// Token paying out if this outcome does not happen
+    pub no_token_id: String,
+}
+
+/// Multi-outcome neg risk market
+///
+/// Neg risk markets group several mutually exclusive binary markets under a
+/// shared `neg_risk_market_id`. At most one outcome resolves Yes, which lets a
+/// No position in one outcome be converted into Yes positions in all others.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NegRiskMarket {
+    /// Shared neg risk market ID
+    pub neg_risk_market_id: String,
+    /// Outcomes making up the market
+    pub outcomes: Vec<NegRiskOutcome>,
+}
+
+impl NegRiskMarket {
+    /// Group CLOB markets into neg risk markets by their `neg_risk_market_id`
+    ///
+    /// Markets that are not neg risk are skipped. Groups keep the order in
+    /// which their first market appears.
+    pub fn from_markets(markets: &[Market]) -> Vec<NegRiskMarket> {
+        let mut grouped: Vec<NegRiskMarket> = Vec::new();
+        for market in markets.iter().filter(|m| m.neg_risk) {
+            let [first, second] = &market.tokens;
+            let (yes, no) = if second.outcome.eq_ignore_ascii_case("yes") {
+                (second, first)
+            } else {
+                (first, second)
+            };
+            let outcome = NegRiskOutcome {
+                condition_id: market.condition_id.clone(),
+                question: market.question.clone(),
+                yes_token_id: yes.token_id.to_string(),
+                no_token_id: no.token_id.to_string(),
+            };
+
+            match grouped
+                .iter_mut()
+                .find(|g| g.neg_risk_market_id == market.neg_risk_market_id)
+            {
+                Some(group) => group.outcomes.push(outcome),
+                None => grouped.push(NegRiskMarket {
+                    neg_risk_market_id: market.neg_risk_market_id.clone(),
+                    outcomes: vec![outcome],
+                }),
+            }
+        }
+        grouped
+    }
+
+    /// Build the neg risk market described by a Gamma event
+    ///
+    /// Returns `None` if the event is not neg risk or its markets don't carry
+    /// a neg risk market ID. Markets without two CLOB token IDs are skipped.
+    pub fn from_event(event: &GammaEvent) -> Option<NegRiskMarket> {
+        if !event.neg_risk {
+            return None;
+        }
+
+        let neg_risk_market_id = event
+            .markets
+            .iter()
+            .find_map(|m| m.neg_risk_market_id.clone())?;
+
+        let outcomes = event
+            .markets
+            .iter()
+            .filter_map(|market| {
+                let token_ids: Vec<String> =
+                    serde_json::from_str(market.clob_token_ids.as_deref()?).ok()?;
+                let outcomes: Vec<String> = market
+                    .outcomes
+                    .as_deref()
+                    .and_then(|o| serde_json::from_str(o).ok())
+                    .unwrap_or_default();
+                let [first, second] = <[String; 2]>::try_from(token_ids).ok()?;
+                let second_is_yes = outcomes
+                    .get(1)
+                    .is_some_and(|o| o.eq_ignore_ascii_case("yes"));
+                let (yes_token_id, no_token_id) = if second_is_yes {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                Some(NegRiskOutcome {
+                    condition_id: market.condition_id.clone(),
+                    question: market.question.clone(),
+                    yes_token_id,
+                    no_token_id,
+                })
+            })
+            .collect();
+
+        Some(NegRiskMarket {
+            neg_risk_market_id,
+            outcomes,
+        })
+    }
+
+    /// Yes token IDs of all outcomes
+    pub fn yes_token_ids(&self) -> Vec<&str> {
+        self.outcomes
+            .iter()
+            .map(|o| o.yes_token_id.as_str())
+            .collect()
+    }
+
+    /// Find the outcome a Yes or No token belongs to
+    pub fn outcome_for_token(&self, token_id: &str) -> Option<&NegRiskOutcome> {
+        self.outcomes
+            .iter()
+            .find(|o| o.yes_token_id == token_id || o.no_token_id == token_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENT: &str = r#"{
+        "id": "1", "ticker": "election", "slug": "election", "title": "Who wins?",
+        "negRisk": true,
+        "markets": [
+            {"id": "10", "question": "Will A win?", "description": "", "slug": "a",
+             "conditionId": "0xa", "negRiskMarketID": "0xnr",
+             "outcomes": "[\"Yes\", \"No\"]", "clobTokenIds": "[\"1\", \"2\"]"},
+            {"id": "11", "question": "Will B win?", "description": "", "slug": "b",
+             "conditionId": "0xb", "negRiskMarketID": "0xnr",
+             "outcomes": "[\"No\", \"Yes\"]", "clobTokenIds": "[\"3\", \"4\"]"},
+            {"id": "12", "question": "Not deployed", "description": "", "slug": "c",
+             "conditionId": "0xc", "negRiskMarketID": "0xnr"}
+        ]
+    }"#;
+
+    #[test]
+    fn test_from_event() {
+        let event: GammaEvent = serde_json::from_str(EVENT).unwrap();
+        let market = NegRiskMarket::from_event(&event).unwrap();
+
+        assert_eq!(market.neg_risk_market_id, "0xnr");
+        assert_eq!(market.outcomes.len(), 2);
+        assert_eq!(market.yes_token_ids(), ["1", "4"]);
+        assert_eq!(market.outcome_for_token("3").unwrap().condition_id, "0xb");
+        assert!(market.outcome_for_token("5").is_none());
+    }
+
+    #[test]
+    fn test_from_event_not_neg_risk() {
+        let mut event: GammaEvent = serde_json::from_str(EVENT).unwrap();
+        event.neg_risk = false;
+        assert!(NegRiskMarket::from_event(&event).is_none());
+    }
+}
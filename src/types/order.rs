@@ -1,19 +1,38 @@
-use super::enums::{OrderType, Side};
-use crate::error::Result;
+use super::enums::{OrderPlacementStatus, OrderStatus, OrderType, Side};
+use super::market::Market;
+use super::websocket::PriceChange;
+use crate::error::{Error, Result};
+use crate::request::Cursor;
 use crate::{orders::calculate_market_price, OrderId};
-use alloy_primitives::U256;
-use rust_decimal::Decimal;
+use alloy_primitives::{Address, U256};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
 
 const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
 
 /// Arguments for creating a limit order
-#[derive(Debug, Clone)]
+///
+/// Serializes with the field names below, prices and sizes as decimal
+/// strings, for storing order intents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderArgs {
     pub token_id: String,
     pub price: Decimal,
     pub size: Decimal,
     pub side: Side,
+    /// Lifetime of a GTD order, counted from when it is created
+    ///
+    /// Converted to an absolute expiration by
+    /// [`OrderBuilder::create_order`](crate::OrderBuilder::create_order) using
+    /// the builder's time source, so an
+    /// [`OffsetTimeSource`](crate::orders::OffsetTimeSource) corrects for
+    /// clock skew. Can't be combined with an explicit `expiration`.
+    #[serde(default)]
+    pub expires_in: Option<Duration>,
 }
 
 impl OrderArgs {
@@ -23,16 +42,55 @@ impl OrderArgs {
             price,
             size,
             side,
+            expires_in: None,
         }
     }
+
+    /// Expire the order `lifetime` after it is created (for GTD orders)
+    ///
+    /// Saves computing `now + lifetime` by hand. The exchange's security
+    /// buffer is added on top, so pass the lifetime the order should
+    /// actually have; it must be at least one second.
+    pub fn expires_in(mut self, lifetime: Duration) -> Self {
+        self.expires_in = Some(lifetime);
+        self
+    }
+
+    /// Create limit order arguments from a collateral (USDC) amount instead of shares
+    ///
+    /// The size is `usdc_amount / price` rounded down to 2 decimal places, the
+    /// share precision used for every tick size. Rounding down means the order
+    /// never commits more than `usdc_amount`.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidParameter`] if `price` is not positive.
+    pub fn from_collateral(
+        token_id: impl Into<String>,
+        price: Decimal,
+        usdc_amount: Decimal,
+        side: Side,
+    ) -> Result<Self> {
+        if price <= Decimal::ZERO {
+            return Err(Error::InvalidParameter(format!(
+                "price must be positive, got {}",
+                price
+            )));
+        }
+        let size = (usdc_amount / price).round_dp_with_strategy(2, RoundingStrategy::ToZero);
+        Ok(Self::new(token_id, price, size, side))
+    }
 }
 
 /// Arguments for creating a market order
 #[derive(Debug, Clone)]
 pub struct MarketOrderArgs {
     pub token_id: String,
+    /// USDC to spend for a buy, shares to sell for a sell
     pub amount: Decimal,
     pub side: Side,
+    /// Size the order down to the available liquidity instead of failing
+    /// when the book can't fill the full amount
+    pub allow_partial: bool,
 }
 
 impl MarketOrderArgs {
@@ -41,24 +99,54 @@ impl MarketOrderArgs {
             token_id: token_id.into(),
             amount,
             side,
+            allow_partial: false,
         }
     }
+
+    pub fn allow_partial(mut self, allow_partial: bool) -> Self {
+        self.allow_partial = allow_partial;
+        self
+    }
 }
 
 /// Extra optional arguments for order creation
-#[derive(Debug, Clone)]
+///
+/// Serializes with the field names below; missing fields take their
+/// [`Default`] values when deserializing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ExtraOrderArgs {
-    pub fee_rate_bps: u32,
+    /// Fee rate in basis points signed into the order.
+    ///
+    /// When unset, the market's base fee from
+    /// [`CreateOrderOptions::fee_rate_bps`] is used, or 0 if that is unset
+    /// too. Values above [`MAX_FEE_RATE_BPS`](crate::orders::MAX_FEE_RATE_BPS)
+    /// are rejected before signing. The exchange charges fees on fills using
+    /// the signed rate, so it should match the market's fee.
+    pub fee_rate_bps: Option<u32>,
     pub nonce: U256,
     pub taker: String,
+    /// Maker (funder) address override for this order.
+    ///
+    /// When set, the order is made by this address instead of the
+    /// `OrderBuilder` funder. The signer is unchanged.
+    pub maker: Option<Address>,
+    /// Post-only (maker-only) order.
+    ///
+    /// Sent as the native `postOnly` flag when the order is posted; the
+    /// exchange rejects the order instead of matching it if it would cross
+    /// the book. Only valid for GTC and GTD orders.
+    pub post_only: bool,
 }
 
 impl Default for ExtraOrderArgs {
     fn default() -> Self {
         Self {
-            fee_rate_bps: 0,
+            fee_rate_bps: None,
             nonce: U256::ZERO,
             taker: ZERO_ADDRESS.into(),
+            maker: None,
+            post_only: false,
         }
     }
 }
@@ -69,7 +157,7 @@ impl ExtraOrderArgs {
     }
 
     pub fn fee_rate_bps(mut self, fee_rate_bps: u32) -> Self {
-        self.fee_rate_bps = fee_rate_bps;
+        self.fee_rate_bps = Some(fee_rate_bps);
         self
     }
 
@@ -82,13 +170,52 @@ impl ExtraOrderArgs {
         self.taker = taker.into();
         self
     }
+
+    pub fn maker(mut self, maker: Address) -> Self {
+        self.maker = Some(maker);
+        self
+    }
+
+    pub fn post_only(mut self, post_only: bool) -> Self {
+        self.post_only = post_only;
+        self
+    }
+
+    /// Default arguments with the fee rate set to the market's taker base fee
+    ///
+    /// The exchange expects orders to carry the market's fee rate. Fee rates
+    /// that don't fit a `u32` are left unset.
+    pub fn from_market(market: &Market) -> Self {
+        Self {
+            fee_rate_bps: market.taker_base_fee.to_u32(),
+            ..Self::new()
+        }
+    }
 }
 
 /// Options for creating orders
+///
+/// `tick_size` and `neg_risk` are required: creating an order without them
+/// fails with [`Error::MissingField`] before anything is signed or fetched.
+/// [`from_market`](Self::from_market) fills in both.
 #[derive(Debug, Clone, Default)]
 pub struct CreateOrderOptions {
+    /// Market tick size (required)
     pub tick_size: Option<Decimal>,
+    /// Whether the market is a negative risk market (required)
     pub neg_risk: Option<bool>,
+    /// Smallest accepted limit order size in shares; smaller orders are
+    /// rejected with [`Error::InvalidOrder`] before signing
+    pub min_order_size: Option<Decimal>,
+    /// Market base fee in basis points, used when
+    /// [`ExtraOrderArgs::fee_rate_bps`] is unset
+    pub fee_rate_bps: Option<u32>,
+    /// How sizes and amounts are rounded to the exchange's precision
+    ///
+    /// Defaults to rounding down, which never spends more than requested; see
+    /// [`RoundingStrategy`](crate::orders::RoundingStrategy) for how the other
+    /// choices can make an order fail for insufficient balance.
+    pub rounding: crate::orders::RoundingStrategy,
 }
 
 impl CreateOrderOptions {
@@ -96,6 +223,22 @@ impl CreateOrderOptions {
         Self::default()
     }
 
+    /// Populate tick size, neg risk flag, minimum order size and base fee from a fetched [`Market`]
+    ///
+    /// Saves separate `get_tick_size`/`get_neg_risk` calls when the market is
+    /// already at hand. Builder calls made afterwards take precedence, so
+    /// `CreateOrderOptions::from_market(&market).tick_size(dec!(0.001))` keeps
+    /// the market's neg risk flag but uses the explicit tick size.
+    pub fn from_market(market: &Market) -> Self {
+        Self {
+            tick_size: Some(market.minimum_tick_size),
+            neg_risk: Some(market.neg_risk),
+            min_order_size: Some(market.minimum_order_size),
+            fee_rate_bps: market.taker_base_fee.to_u32(),
+            rounding: Default::default(),
+        }
+    }
+
     pub fn tick_size(mut self, tick_size: Decimal) -> Self {
         self.tick_size = Some(tick_size);
         self
@@ -105,9 +248,40 @@ impl CreateOrderOptions {
         self.neg_risk = Some(neg_risk);
         self
     }
+
+    pub fn min_order_size(mut self, min_order_size: Decimal) -> Self {
+        self.min_order_size = Some(min_order_size);
+        self
+    }
+
+    pub fn fee_rate_bps(mut self, fee_rate_bps: u32) -> Self {
+        self.fee_rate_bps = Some(fee_rate_bps);
+        self
+    }
+
+    pub fn rounding(mut self, rounding: crate::orders::RoundingStrategy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Tick size and neg risk flag, or [`Error::MissingField`] naming the
+    /// first one unset
+    pub(crate) fn required(&self) -> Result<(Decimal, bool)> {
+        let tick_size = self
+            .tick_size
+            .ok_or_else(|| Error::MissingField("tick_size".to_string()))?;
+        let neg_risk = self
+            .neg_risk
+            .ok_or_else(|| Error::MissingField("neg_risk".to_string()))?;
+        Ok((tick_size, neg_risk))
+    }
 }
 
 /// Signed order request ready to be posted
+///
+/// Serializes to the exchange's order format, so an order can be signed now,
+/// stored, and deserialized to be posted later. The signature stays valid
+/// until the order's expiration, or until the maker's nonce is incremented.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SignedOrderRequest {
@@ -124,6 +298,46 @@ pub struct SignedOrderRequest {
     pub side: String,
     pub signature_type: u8,
     pub signature: String,
+    /// Post-only flag carried from [`ExtraOrderArgs::post_only`].
+    ///
+    /// Not part of the signed order; stored as `postOnly` when set, and sent
+    /// alongside the order rather than in it when posting.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub post_only: bool,
+}
+
+impl SignedOrderRequest {
+    /// Body of a `POST /order` request for this order, as JSON
+    ///
+    /// For submitting orders signed here through other infrastructure, e.g.
+    /// a relayer or an air-gapped setup. `owner` is the API key the order is
+    /// posted under, as in [`PostOrder::new`]. The post-only flag is checked
+    /// against `order_type` like [`TradingClient::post_order`](crate::TradingClient::post_order)
+    /// does.
+    ///
+    /// The L2 signature covers the exact body bytes, and re-serializing this
+    /// value may reorder its keys; sign and send
+    /// [`to_submission_body`](Self::to_submission_body) instead.
+    pub fn to_submission_json(
+        &self,
+        owner: &str,
+        order_type: OrderType,
+    ) -> Result<serde_json::Value> {
+        let post_order = PostOrder::new(self.clone(), owner.to_string(), order_type);
+        post_order.validate()?;
+        Ok(serde_json::to_value(&post_order)?)
+    }
+
+    /// Body of a `POST /order` request for this order, byte for byte as
+    /// [`TradingClient::post_order`](crate::TradingClient::post_order) sends it
+    ///
+    /// Use this string both as the request body and as the body signed into
+    /// the L2 `POLY_SIGNATURE` header.
+    pub fn to_submission_body(&self, owner: &str, order_type: OrderType) -> Result<String> {
+        let post_order = PostOrder::new(self.clone(), owner.to_string(), order_type);
+        post_order.validate()?;
+        Ok(serde_json::to_string(&post_order)?)
+    }
 }
 
 /// Order to be posted to the API
@@ -133,15 +347,35 @@ pub struct PostOrder {
     order: SignedOrderRequest,
     owner: String,
     order_type: OrderType,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    post_only: bool,
 }
 
 impl PostOrder {
-    pub fn new(order: SignedOrderRequest, owner: String, order_type: OrderType) -> Self {
+    /// Wrap a signed order for posting
+    ///
+    /// `owner` is the API key of the account the order is booked under,
+    /// normally the key that authenticates the request; see
+    /// [`TradingClient::post_order_with_owner`](crate::TradingClient::post_order_with_owner).
+    pub fn new(mut order: SignedOrderRequest, owner: String, order_type: OrderType) -> Self {
+        let post_only = std::mem::take(&mut order.post_only);
         Self {
             order,
             owner,
             order_type,
+            post_only,
+        }
+    }
+
+    /// Check that the order type is compatible with the post-only flag
+    pub fn validate(&self) -> Result<()> {
+        if self.post_only && matches!(self.order_type, OrderType::Fok | OrderType::Fak) {
+            return Err(Error::InvalidOrder(format!(
+                "post-only orders must be GTC or GTD, got {:?}",
+                self.order_type
+            )));
         }
+        Ok(())
     }
 }
 
@@ -154,22 +388,29 @@ pub struct OpenOrdersResponse {
     pub data: Vec<OpenOrder>,
 }
 
+impl OpenOrdersResponse {
+    /// Cursor of the next page, or None on the last page
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::next(self.next_cursor.as_deref())
+    }
+}
+
 /// Open order from the API
 #[derive(Debug, Deserialize)]
 pub struct OpenOrder {
     pub id: OrderId,
     pub associate_trades: Vec<String>,
-    pub status: String,
+    pub status: OrderStatus,
     pub market: String,
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub original_size: Decimal,
     pub outcome: String,
     pub maker_address: String,
     pub owner: String,
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub price: Decimal,
     pub side: Side,
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub size_matched: Decimal,
     pub asset_id: String,
     #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
@@ -179,12 +420,40 @@ pub struct OpenOrder {
     pub created_at: u64,
 }
 
+impl OpenOrder {
+    /// Size still open on the book (`original_size - size_matched`, never negative)
+    pub fn remaining_size(&self) -> Decimal {
+        (self.original_size - self.size_matched).max(Decimal::ZERO)
+    }
+
+    /// Filled percentage of the original size, from 0 to 100
+    pub fn fill_pct(&self) -> Decimal {
+        if self.original_size <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        (self.size_matched / self.original_size * Decimal::ONE_HUNDRED)
+            .clamp(Decimal::ZERO, Decimal::ONE_HUNDRED)
+    }
+
+    /// Returns true if the whole original size has been matched
+    pub fn is_fully_filled(&self) -> bool {
+        self.original_size > Decimal::ZERO && self.remaining_size().is_zero()
+    }
+
+    /// Returns true if some, but not all, of the original size has been matched
+    pub fn is_partially_filled(&self) -> bool {
+        self.size_matched > Decimal::ZERO && !self.is_fully_filled()
+    }
+}
+
 /// Parameters for querying open orders
 #[derive(Debug, Clone, Default)]
 pub struct OpenOrderParams {
     pub id: Option<String>,
     pub asset_id: Option<String>,
     pub market: Option<String>,
+    /// Pagination cursor (see [`END_CURSOR`](crate::request::END_CURSOR))
+    pub next_cursor: Option<String>,
 }
 
 impl OpenOrderParams {
@@ -207,8 +476,13 @@ impl OpenOrderParams {
         self
     }
 
+    pub fn next_cursor(mut self, next_cursor: impl Into<String>) -> Self {
+        self.next_cursor = Some(next_cursor.into());
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, &String)> {
-        let mut params = Vec::with_capacity(3);
+        let mut params = Vec::with_capacity(4);
 
         if let Some(ref id) = self.id {
             params.push(("id", id));
@@ -222,6 +496,10 @@ impl OpenOrderParams {
             params.push(("market", market));
         }
 
+        if let Some(ref next_cursor) = self.next_cursor {
+            params.push(("next_cursor", next_cursor));
+        }
+
         params
     }
 }
@@ -230,13 +508,25 @@ impl OpenOrderParams {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct PriceLevel {
     /// Price at this level
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub price: Decimal,
     /// Total size available at this price
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub size: Decimal,
 }
 
+impl PriceLevel {
+    /// Whether this level removes its price from the book
+    ///
+    /// Book updates carry the new total size at a price, so a size of zero,
+    /// at any scale (`"0"`, `"0.00"`), means the level is gone. Negative
+    /// sizes, which the server doesn't send, are treated the same. Any other
+    /// size replaces the level, or inserts it if the price wasn't on the book.
+    pub fn is_removal(&self) -> bool {
+        self.size <= Decimal::ZERO
+    }
+}
+
 /// Order book summary with bids and asks
 #[derive(Debug, Deserialize)]
 pub struct OrderBookSummary {
@@ -265,17 +555,143 @@ impl OrderBookSummary {
         self.bids.is_empty() && self.asks.is_empty()
     }
 
+    /// Highest bid price, or None if there are no bids
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.iter().map(|l| l.price).max()
+    }
+
+    /// Lowest ask price, or None if there are no asks
+    pub fn best_ask(&self) -> Option<Decimal> {
+        self.asks.iter().map(|l| l.price).min()
+    }
+
     pub fn sort_bids(&self) -> Vec<PriceLevel> {
         let mut bids = self.bids.clone();
-        bids.sort_by(|a, b| b.price.cmp(&a.price));
+        bids.sort_by_key(|b| std::cmp::Reverse(b.price));
         bids
     }
 
     pub fn sort_asks(&self) -> Vec<PriceLevel> {
         let mut asks = self.asks.clone();
-        asks.sort_by(|a, b| a.price.cmp(&b.price));
+        asks.sort_by_key(|a| a.price);
         asks
     }
+
+    /// Hash of the book in the format of the `hash` field sent by the exchange
+    ///
+    /// The exchange doesn't document its book hash. This implements the
+    /// scheme used by the official Python client: the SHA-1 hex digest of the
+    /// compact JSON object
+    /// `{"market":…,"asset_id":…,"timestamp":"…","bids":[{"price":"…","size":"…"},…],"asks":[…],"hash":""}`,
+    /// with fields in that order, numbers as strings exactly as received and
+    /// levels in the order the server sent them.
+    ///
+    /// Because prices and sizes are hashed as text, levels must keep the
+    /// formatting they arrived with (e.g. `"0.50"` vs `"0.5"`), and `timestamp`
+    /// must be the one of the message the hash came with.
+    pub fn compute_hash(&self) -> String {
+        #[derive(Serialize)]
+        struct Level {
+            price: String,
+            size: String,
+        }
+
+        #[derive(Serialize)]
+        struct HashInput<'a> {
+            market: &'a str,
+            asset_id: &'a str,
+            timestamp: String,
+            bids: Vec<Level>,
+            asks: Vec<Level>,
+            hash: &'a str,
+        }
+
+        let levels = |levels: &[PriceLevel]| {
+            levels
+                .iter()
+                .map(|l| Level {
+                    price: l.price.to_string(),
+                    size: l.size.to_string(),
+                })
+                .collect()
+        };
+        let input = HashInput {
+            market: &self.market,
+            asset_id: &self.asset_id,
+            timestamp: self.timestamp.to_string(),
+            bids: levels(&self.bids),
+            asks: levels(&self.asks),
+            hash: "",
+        };
+        let json = serde_json::to_string(&input).expect("book hash input is always serializable");
+        alloy_primitives::hex::encode(Sha1::digest(json.as_bytes()))
+    }
+
+    /// Whether [`compute_hash`](Self::compute_hash) matches `expected`
+    ///
+    /// Compare against the `hash` of a `book` event, or of the latest
+    /// `price_change` after applying it to a locally maintained book. A
+    /// mismatch means the local book has drifted, e.g. after a missed
+    /// message, and should be rebuilt by resubscribing or refetching the
+    /// book. Comparison is case-insensitive.
+    pub fn verify_checksum(&self, expected: &str) -> bool {
+        self.compute_hash().eq_ignore_ascii_case(expected)
+    }
+
+    /// Keep only the best `levels` price levels on each side
+    ///
+    /// Bids are returned best (highest) first and asks best (lowest) first.
+    pub fn truncate(&mut self, levels: usize) {
+        self.bids.sort_by_key(|b| std::cmp::Reverse(b.price));
+        self.bids.truncate(levels);
+        self.asks.sort_by_key(|a| a.price);
+        self.asks.truncate(levels);
+    }
+
+    /// Price level changes that turn this book into `other`
+    ///
+    /// Added and resized levels carry their new size in `other`; removed levels
+    /// carry a size of 0, matching the `price_change` WebSocket events. Bids are
+    /// reported as `BUY` and asks as `SELL`, each in ascending price order.
+    /// Levels listed more than once at the same price are summed.
+    ///
+    /// Applying the result to this book yields `other`, so this can check a
+    /// locally maintained book against a fresh REST snapshot.
+    pub fn diff(&self, other: &OrderBookSummary) -> Vec<PriceChange> {
+        let mut changes = Vec::new();
+        for (side, old, new) in [
+            (Side::Buy, &self.bids, &other.bids),
+            (Side::Sell, &self.asks, &other.asks),
+        ] {
+            let old = level_sizes(old);
+            let new = level_sizes(new);
+            let prices: BTreeSet<Decimal> = old.keys().chain(new.keys()).copied().collect();
+            for price in prices {
+                let size = new.get(&price).copied().unwrap_or(Decimal::ZERO);
+                if old.get(&price).copied().unwrap_or(Decimal::ZERO) != size {
+                    changes.push(PriceChange {
+                        asset_id: other.asset_id.clone(),
+                        side,
+                        price,
+                        size,
+                        hash: None,
+                        best_bid: None,
+                        best_ask: None,
+                    });
+                }
+            }
+        }
+        changes
+    }
+}
+
+/// Total size per price, skipping empty levels
+fn level_sizes(levels: &[PriceLevel]) -> BTreeMap<Decimal, Decimal> {
+    let mut sizes = BTreeMap::new();
+    for level in levels.iter().filter(|l| !l.is_removal()) {
+        *sizes.entry(level.price).or_insert(Decimal::ZERO) += level.size;
+    }
+    sizes
 }
 
 /// Parameters for querying order book
@@ -295,14 +711,38 @@ impl BookParams {
 }
 
 /// Response from posting an order
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PostOrderResponse {
+    #[serde(default)]
     pub error_msg: String,
     #[serde(rename = "orderID")]
     pub order_id: OrderId,
+    /// Raw placement status (see [`placement_status`](Self::placement_status))
     pub status: String,
     pub success: bool,
+    /// Amount received on placement, if the order was (partially) matched
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub taking_amount: Option<Decimal>,
+    /// Amount given on placement, if the order was (partially) matched
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub making_amount: Option<Decimal>,
+    /// Settlement transaction hashes for matched orders
+    #[serde(default, rename = "transactionsHashes")]
+    pub transactions_hashes: Vec<String>,
+}
+
+impl PostOrderResponse {
+    /// Typed placement status parsed from `status` and `success`
+    pub fn placement_status(&self) -> OrderPlacementStatus {
+        OrderPlacementStatus::from_response(&self.status, self.success)
+    }
 }
 
 /// Arguments for posting multiple orders
@@ -325,8 +765,370 @@ impl PostOrderArgs {
 /// - `cancel_orders` - Cancel multiple orders
 /// - `cancel_all` - Cancel all orders
 /// - `cancel_market_orders` - Cancel orders by market/asset
+///
+/// All four endpoints answer with the same shape, so the counts below mean
+/// the same thing whichever method was used. `cancel_all` and
+/// `cancel_market_orders` may send `null` or omit a field when there was
+/// nothing to cancel; both are read as empty.
 #[derive(Debug, Deserialize)]
 pub struct CancelOrdersResponse {
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_null_default"
+    )]
     pub canceled: Vec<OrderId>,
+    /// Reasons keyed by order ID for orders that weren't canceled
+    #[serde(default)]
     pub not_canceled: serde_json::Value,
 }
+
+impl CancelOrdersResponse {
+    /// Number of orders canceled
+    pub fn count_canceled(&self) -> usize {
+        self.canceled.len()
+    }
+
+    /// Number of orders that couldn't be canceled
+    pub fn count_not_canceled(&self) -> usize {
+        match &self.not_canceled {
+            serde_json::Value::Object(reasons) => reasons.len(),
+            serde_json::Value::Array(ids) => ids.len(),
+            _ => 0,
+        }
+    }
+
+    /// Fold the results of another cancel request into this one
+    ///
+    /// Canceled IDs are appended and `not_canceled` entries are merged, with
+    /// `other` winning on duplicate IDs.
+    pub fn merge(&mut self, other: CancelOrdersResponse) {
+        self.canceled.extend(other.canceled);
+        match (&mut self.not_canceled, other.not_canceled) {
+            (serde_json::Value::Object(ours), serde_json::Value::Object(theirs)) => {
+                ours.extend(theirs)
+            }
+            (ours, theirs) if ours.is_null() => *ours = theirs,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn signed_order(post_only: bool) -> SignedOrderRequest {
+        SignedOrderRequest {
+            salt: 1,
+            maker: ZERO_ADDRESS.to_string(),
+            signer: ZERO_ADDRESS.to_string(),
+            taker: ZERO_ADDRESS.to_string(),
+            token_id: "1234".to_string(),
+            maker_amount: "5000000".to_string(),
+            taker_amount: "10000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "0".to_string(),
+            side: "BUY".to_string(),
+            signature_type: 0,
+            signature: "0x".to_string(),
+            post_only,
+        }
+    }
+
+    fn open_order(original_size: &str, size_matched: &str) -> OpenOrder {
+        let json = format!(
+            r#"{{"id": "0x1", "associate_trades": [], "status": "LIVE", "market": "0x2",
+                "original_size": "{}", "outcome": "Yes", "maker_address": "0x3",
+                "owner": "owner", "price": "0.5", "side": "BUY", "size_matched": "{}",
+                "asset_id": "1", "expiration": "0", "order_type": "GTC", "created_at": 1}}"#,
+            original_size, size_matched
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_order_args_from_collateral() {
+        let args = OrderArgs::from_collateral("1", dec!(0.3), dec!(10), Side::Buy).unwrap();
+        assert_eq!(args.size, dec!(33.33));
+        assert!(args.size * args.price <= dec!(10));
+
+        let args = OrderArgs::from_collateral("1", dec!(0.5), dec!(10), Side::Sell).unwrap();
+        assert_eq!(args.size, dec!(20));
+
+        assert!(OrderArgs::from_collateral("1", Decimal::ZERO, dec!(10), Side::Buy).is_err());
+    }
+
+    #[test]
+    fn test_open_order_fill_helpers() {
+        let order = open_order("10", "0");
+        assert_eq!(order.remaining_size(), Decimal::from(10));
+        assert_eq!(order.fill_pct(), Decimal::ZERO);
+        assert!(!order.is_partially_filled());
+        assert!(!order.is_fully_filled());
+
+        let order = open_order("10", "2.5");
+        assert_eq!(order.remaining_size(), Decimal::new(75, 1));
+        assert_eq!(order.fill_pct(), Decimal::from(25));
+        assert!(order.is_partially_filled());
+        assert!(!order.is_fully_filled());
+
+        let order = open_order("10", "10");
+        assert_eq!(order.remaining_size(), Decimal::ZERO);
+        assert_eq!(order.fill_pct(), Decimal::ONE_HUNDRED);
+        assert!(!order.is_partially_filled());
+        assert!(order.is_fully_filled());
+    }
+
+    #[test]
+    fn test_open_order_overfill_is_clamped() {
+        let order = open_order("10", "12");
+        assert_eq!(order.remaining_size(), Decimal::ZERO);
+        assert_eq!(order.fill_pct(), Decimal::ONE_HUNDRED);
+        assert!(order.is_fully_filled());
+
+        let order = open_order("0", "0");
+        assert_eq!(order.fill_pct(), Decimal::ZERO);
+        assert!(!order.is_fully_filled());
+    }
+
+    #[test]
+    fn test_order_book_truncate() {
+        let level = |price: i64, size: i64| PriceLevel {
+            price: Decimal::new(price, 2),
+            size: Decimal::from(size),
+        };
+        let mut book = OrderBookSummary {
+            market: "market".to_string(),
+            asset_id: "asset".to_string(),
+            hash: "hash".to_string(),
+            timestamp: 0,
+            bids: vec![level(48, 1), level(50, 2), level(49, 3)],
+            asks: vec![level(53, 1), level(51, 2), level(52, 3)],
+        };
+
+        assert_eq!(book.best_bid(), Some(Decimal::new(50, 2)));
+        assert_eq!(book.best_ask(), Some(Decimal::new(51, 2)));
+
+        book.truncate(2);
+
+        let bid_prices: Vec<_> = book.bids.iter().map(|l| l.price).collect();
+        let ask_prices: Vec<_> = book.asks.iter().map(|l| l.price).collect();
+        assert_eq!(bid_prices, vec![Decimal::new(50, 2), Decimal::new(49, 2)]);
+        assert_eq!(ask_prices, vec![Decimal::new(51, 2), Decimal::new(52, 2)]);
+
+        book.asks.clear();
+        assert_eq!(book.best_ask(), None);
+    }
+
+    #[test]
+    fn test_post_order_post_only_serialization() {
+        let post_order = PostOrder::new(signed_order(false), "owner".into(), OrderType::Gtc);
+        let json = serde_json::to_value(&post_order).unwrap();
+        assert!(json.get("postOnly").is_none());
+        assert!(json["order"].get("postOnly").is_none());
+
+        let post_order = PostOrder::new(signed_order(true), "owner".into(), OrderType::Gtc);
+        let json = serde_json::to_value(&post_order).unwrap();
+        assert_eq!(json["postOnly"], true);
+        assert!(json["order"].get("postOnly").is_none());
+    }
+
+    #[test]
+    fn test_order_types_round_trip() {
+        let order = signed_order(true);
+        let json = serde_json::to_string(&order).unwrap();
+        let restored: SignedOrderRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        assert!(restored.post_only);
+        assert_eq!(restored.signature, order.signature);
+
+        let args = OrderArgs::new("1234", dec!(0.45), dec!(100), Side::Buy)
+            .expires_in(Duration::from_secs(3600));
+        let json = serde_json::to_value(&args).unwrap();
+        assert_eq!(json["price"], "0.45");
+        assert_eq!(json["side"], "BUY");
+        let restored: OrderArgs = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.price, args.price);
+        assert_eq!(restored.expires_in, args.expires_in);
+
+        let extras = ExtraOrderArgs::new()
+            .fee_rate_bps(10)
+            .nonce(U256::from(7))
+            .post_only(true);
+        let json = serde_json::to_string(&extras).unwrap();
+        let restored: ExtraOrderArgs = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        assert_eq!(restored.nonce, U256::from(7));
+
+        let defaults: ExtraOrderArgs = serde_json::from_str(r#"{"post_only": true}"#).unwrap();
+        assert!(defaults.post_only);
+        assert_eq!(defaults.taker, ZERO_ADDRESS);
+    }
+
+    #[test]
+    fn test_cancel_orders_response_counts() {
+        let response: CancelOrdersResponse = serde_json::from_str(
+            r#"{"canceled": ["0xa", "0xb"], "not_canceled": {"0xc": "order not found"}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.count_canceled(), 2);
+        assert_eq!(response.count_not_canceled(), 1);
+
+        // Nothing to cancel
+        for json in [r#"{"canceled": null, "not_canceled": null}"#, "{}"] {
+            let response: CancelOrdersResponse = serde_json::from_str(json).unwrap();
+            assert_eq!(response.count_canceled(), 0);
+            assert_eq!(response.count_not_canceled(), 0);
+        }
+    }
+
+    #[test]
+    fn test_post_order_response_matched() {
+        let json = r#"{
+            "errorMsg": "",
+            "orderID": "0xabc",
+            "takingAmount": "10",
+            "makingAmount": "5.2",
+            "status": "matched",
+            "transactionsHashes": ["0xdef"],
+            "success": true
+        }"#;
+        let resp: PostOrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.placement_status(), OrderPlacementStatus::Matched);
+        assert_eq!(resp.order_id.as_str(), "0xabc");
+        assert_eq!(resp.taking_amount, Some(Decimal::from(10)));
+        assert_eq!(resp.making_amount, Some(Decimal::new(52, 1)));
+        assert_eq!(resp.transactions_hashes, vec!["0xdef".to_string()]);
+    }
+
+    #[test]
+    fn test_post_order_response_live() {
+        let json = r#"{
+            "errorMsg": "",
+            "orderID": "0xabc",
+            "takingAmount": "",
+            "makingAmount": "",
+            "status": "live",
+            "success": true
+        }"#;
+        let resp: PostOrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.placement_status(), OrderPlacementStatus::Live);
+        assert_eq!(resp.taking_amount, None);
+        assert!(resp.transactions_hashes.is_empty());
+    }
+
+    #[test]
+    fn test_placement_status_parsing() {
+        assert_eq!(
+            OrderPlacementStatus::from_response("delayed", true),
+            OrderPlacementStatus::Delayed
+        );
+        assert_eq!(
+            OrderPlacementStatus::from_response("UNMATCHED", true),
+            OrderPlacementStatus::Unmatched
+        );
+        assert_eq!(
+            OrderPlacementStatus::from_response("live", false),
+            OrderPlacementStatus::Failed
+        );
+        assert_eq!(
+            OrderPlacementStatus::from_response("pending", true),
+            OrderPlacementStatus::Unknown("pending".to_string())
+        );
+    }
+
+    #[test]
+    fn test_post_only_rejects_immediate_order_types() {
+        for order_type in [OrderType::Gtc, OrderType::Gtd] {
+            assert!(
+                PostOrder::new(signed_order(true), "owner".into(), order_type)
+                    .validate()
+                    .is_ok()
+            );
+        }
+        for order_type in [OrderType::Fok, OrderType::Fak] {
+            assert!(
+                PostOrder::new(signed_order(true), "owner".into(), order_type)
+                    .validate()
+                    .is_err()
+            );
+            assert!(
+                PostOrder::new(signed_order(false), "owner".into(), order_type)
+                    .validate()
+                    .is_ok()
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_book_diff() {
+        let level = |price: i64, size: i64| PriceLevel {
+            price: Decimal::new(price, 2),
+            size: Decimal::from(size),
+        };
+        let book = |bids, asks| OrderBookSummary {
+            market: "market".to_string(),
+            asset_id: "asset".to_string(),
+            hash: "hash".to_string(),
+            timestamp: 0,
+            bids,
+            asks,
+        };
+        let before = book(vec![level(49, 3), level(50, 2)], vec![level(51, 2)]);
+        // 0.50 bid resized, 0.49 bid removed, 0.48 bid added, asks unchanged
+        let after = book(vec![level(48, 1), level(50, 5)], vec![level(51, 2)]);
+
+        let changes: Vec<(Side, Decimal, Decimal)> = before
+            .diff(&after)
+            .into_iter()
+            .map(|c| (c.side, c.price, c.size))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                (Side::Buy, Decimal::new(48, 2), Decimal::from(1)),
+                (Side::Buy, Decimal::new(49, 2), Decimal::ZERO),
+                (Side::Buy, Decimal::new(50, 2), Decimal::from(5)),
+            ]
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+
+    #[test]
+    fn test_book_checksum() {
+        let book = OrderBookSummary {
+            market: "0x1".to_string(),
+            asset_id: "123".to_string(),
+            hash: String::new(),
+            timestamp: 1700000000000,
+            bids: vec![
+                PriceLevel {
+                    price: dec!(0.48),
+                    size: dec!(30),
+                },
+                PriceLevel {
+                    price: dec!(0.49),
+                    size: dec!(20.5),
+                },
+            ],
+            asks: vec![PriceLevel {
+                price: dec!(0.52),
+                size: dec!(15),
+            }],
+        };
+
+        // Reference digest computed with Python's json.dumps + hashlib.sha1
+        assert_eq!(
+            book.compute_hash(),
+            "31a0139b3ab67d30ef5afe6471b98d75be5f5236"
+        );
+        assert!(book.verify_checksum("31A0139B3AB67D30EF5AFE6471B98D75BE5F5236"));
+
+        let expected = book.compute_hash();
+        let mut drifted = book;
+        drifted.bids[0].size = dec!(29);
+        assert!(!drifted.verify_checksum(&expected));
+    }
+}
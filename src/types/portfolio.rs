@@ -0,0 +1,127 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::primitives::TokenId;
+use super::trade::Position;
+
+/// PNL of a single position, marked at the current price
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionPnl {
+    /// Token ID of the position
+    pub asset: String,
+    pub condition_id: String,
+    pub title: String,
+    pub outcome: String,
+    pub size: Decimal,
+    pub avg_price: Decimal,
+    /// Price the position is marked at (midpoint, or the data API's price)
+    pub mark_price: Decimal,
+    /// `size * avg_price`
+    pub cost_basis: Decimal,
+    /// `size * mark_price`
+    pub value: Decimal,
+    /// `value - cost_basis`
+    pub unrealized_pnl: Decimal,
+    /// Realized PNL as reported by the data API
+    pub realized_pnl: Decimal,
+}
+
+/// Account summary: per-position and aggregate PNL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortfolioPnl {
+    pub positions: Vec<PositionPnl>,
+    /// Sum of position values
+    pub total_value: Decimal,
+    /// Sum of position cost bases
+    pub total_cost: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub realized_pnl: Decimal,
+    /// `unrealized_pnl + realized_pnl`
+    pub total_pnl: Decimal,
+}
+
+impl PortfolioPnl {
+    /// Compute PNL for `positions`, marking each at its price in `prices`
+    ///
+    /// Positions without an entry in `prices` (e.g. resolved markets with no
+    /// order book) are marked at their `cur_price` from the data API.
+    pub fn from_positions(positions: &[Position], prices: &HashMap<TokenId, Decimal>) -> Self {
+        let positions: Vec<PositionPnl> = positions
+            .iter()
+            .map(|p| {
+                let mark_price = prices
+                    .get(&TokenId::new(p.asset.clone()))
+                    .copied()
+                    .unwrap_or(p.cur_price);
+                let cost_basis = p.size * p.avg_price;
+                let value = p.size * mark_price;
+                PositionPnl {
+                    asset: p.asset.clone(),
+                    condition_id: p.condition_id.clone(),
+                    title: p.title.clone(),
+                    outcome: p.outcome.clone(),
+                    size: p.size,
+                    avg_price: p.avg_price,
+                    mark_price,
+                    cost_basis,
+                    value,
+                    unrealized_pnl: value - cost_basis,
+                    realized_pnl: p.realized_pnl,
+                }
+            })
+            .collect();
+
+        let total_value = positions.iter().map(|p| p.value).sum();
+        let total_cost = positions.iter().map(|p| p.cost_basis).sum();
+        let unrealized_pnl: Decimal = positions.iter().map(|p| p.unrealized_pnl).sum();
+        let realized_pnl: Decimal = positions.iter().map(|p| p.realized_pnl).sum();
+
+        Self {
+            positions,
+            total_value,
+            total_cost,
+            unrealized_pnl,
+            realized_pnl,
+            total_pnl: unrealized_pnl + realized_pnl,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn position(asset: &str, size: Decimal, avg_price: Decimal, cur_price: Decimal) -> Position {
+        Position {
+            asset: asset.to_string(),
+            size,
+            avg_price,
+            cur_price,
+            realized_pnl: dec!(1),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_positions() {
+        let positions = [
+            position("1", dec!(100), dec!(0.40), dec!(0.45)),
+            position("2", dec!(50), dec!(0.80), dec!(1)),
+        ];
+        // Token 2 has no midpoint and falls back to cur_price
+        let prices = HashMap::from([(TokenId::new("1"), dec!(0.55))]);
+
+        let pnl = PortfolioPnl::from_positions(&positions, &prices);
+        assert_eq!(pnl.positions[0].mark_price, dec!(0.55));
+        assert_eq!(pnl.positions[0].unrealized_pnl, dec!(15));
+        assert_eq!(pnl.positions[1].mark_price, dec!(1));
+        assert_eq!(pnl.positions[1].unrealized_pnl, dec!(10));
+        assert_eq!(pnl.total_value, dec!(105));
+        assert_eq!(pnl.total_cost, dec!(80));
+        assert_eq!(pnl.unrealized_pnl, dec!(25));
+        assert_eq!(pnl.realized_pnl, dec!(2));
+        assert_eq!(pnl.total_pnl, dec!(27));
+    }
+}
@@ -1,8 +1,12 @@
+use crate::error::Error;
+use alloy_primitives::U256;
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use std::fmt;
+use std::str::FromStr;
 
 /// Type-safe token identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct TokenId(String);
 
@@ -18,6 +22,30 @@ impl TokenId {
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    /// Convert a hex token ID (with or without `0x`) to the decimal form the
+    /// API expects
+    ///
+    /// Returns [`Error::InvalidParameter`] unless the input is a hex integer
+    /// that fits in 256 bits.
+    pub fn from_hex(hex: &str) -> Result<Self, Error> {
+        let trimmed = hex.trim();
+        let digits = trimmed
+            .strip_prefix("0x")
+            .or_else(|| trimmed.strip_prefix("0X"))
+            .unwrap_or(trimmed);
+        let value = parse_u256(digits, 16)
+            .ok_or_else(|| Error::InvalidParameter(format!("Invalid hex token ID: {:?}", hex)))?;
+        Ok(Self(value.to_string()))
+    }
+}
+
+/// Parse a non-empty digit string without sign or separators
+fn parse_u256(digits: &str, radix: u64) -> Option<U256> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix as u32)) {
+        return None;
+    }
+    U256::from_str_radix(digits, radix).ok()
 }
 
 impl fmt::Display for TokenId {
@@ -38,6 +66,21 @@ impl From<&str> for TokenId {
     }
 }
 
+/// Parse and canonicalize a decimal token ID
+///
+/// Surrounding whitespace and leading zeros are removed. Returns
+/// [`Error::InvalidParameter`] unless the input is a decimal integer that fits
+/// in 256 bits; use [`TokenId::from_hex`] for hex IDs.
+impl FromStr for TokenId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = parse_u256(s.trim(), 10)
+            .ok_or_else(|| Error::InvalidParameter(format!("Invalid token ID: {:?}", s)))?;
+        Ok(Self(value.to_string()))
+    }
+}
+
 impl AsRef<str> for TokenId {
     fn as_ref(&self) -> &str {
         &self.0
@@ -45,7 +88,7 @@ impl AsRef<str> for TokenId {
 }
 
 /// Type-safe condition identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct ConditionId(String);
 
@@ -81,6 +124,14 @@ impl From<&str> for ConditionId {
     }
 }
 
+impl FromStr for ConditionId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for ConditionId {
     fn as_ref(&self) -> &str {
         &self.0
@@ -88,7 +139,7 @@ impl AsRef<str> for ConditionId {
 }
 
 /// Type-safe order identifier
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct OrderId(String);
 
@@ -124,6 +175,14 @@ impl From<&str> for OrderId {
     }
 }
 
+impl FromStr for OrderId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for OrderId {
     fn as_ref(&self) -> &str {
         &self.0
@@ -131,7 +190,7 @@ impl AsRef<str> for OrderId {
 }
 
 /// Type-safe market slug
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct MarketSlug(String);
 
@@ -167,8 +226,80 @@ impl From<&str> for MarketSlug {
     }
 }
 
+impl FromStr for MarketSlug {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
 impl AsRef<str> for MarketSlug {
     fn as_ref(&self) -> &str {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn test_ids_as_hash_map_keys() {
+        let mut books: HashMap<TokenId, u32> = HashMap::new();
+        books.insert(TokenId::new("1"), 1);
+        books.insert("2".into(), 2);
+        assert_eq!(books.get(&TokenId::new("1")), Some(&1));
+        assert_eq!(books.get(&TokenId::new("2")), Some(&2));
+
+        let mut scoring: HashMap<OrderId, bool> = HashMap::new();
+        scoring.insert(OrderId::new("0xabc"), true);
+        assert_eq!(scoring.get(&OrderId::new("0xabc")), Some(&true));
+    }
+
+    #[test]
+    fn test_ids_as_btree_map_keys() {
+        let mut markets: BTreeMap<ConditionId, &str> = BTreeMap::new();
+        markets.insert(ConditionId::new("0xb"), "b");
+        markets.insert(ConditionId::new("0xa"), "a");
+        let keys: Vec<&str> = markets.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, vec!["0xa", "0xb"]);
+    }
+
+    #[test]
+    fn test_ids_from_str_display_round_trip() {
+        let token_id: TokenId = "12345".parse().unwrap();
+        assert_eq!(token_id.to_string(), "12345");
+        let order_id: OrderId = "0xabc".parse().unwrap();
+        assert_eq!(order_id.to_string(), "0xabc");
+        let condition_id: ConditionId = "0xdef".parse().unwrap();
+        assert_eq!(condition_id.to_string(), "0xdef");
+    }
+
+    #[test]
+    fn test_token_id_from_str_normalizes() {
+        let token_id: TokenId = " 0012345\n".parse().unwrap();
+        assert_eq!(token_id.as_str(), "12345");
+
+        for bad in ["", "12a", "-1", "1.5", "0x1f", "1 2"] {
+            assert!(
+                matches!(bad.parse::<TokenId>(), Err(Error::InvalidParameter(_))),
+                "{:?} should be rejected",
+                bad
+            );
+        }
+        // One more than U256::MAX
+        let too_big =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert!(too_big.parse::<TokenId>().is_err());
+    }
+
+    #[test]
+    fn test_token_id_from_hex() {
+        assert_eq!(TokenId::from_hex("0x3039").unwrap().as_str(), "12345");
+        assert_eq!(TokenId::from_hex(" ff ").unwrap().as_str(), "255");
+        assert!(TokenId::from_hex("0x").is_err());
+        assert!(TokenId::from_hex("0xzz").is_err());
+    }
+}
@@ -1,5 +1,4 @@
 use chrono::{DateTime, Utc};
-use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer};
 use std::fmt::Display;
@@ -25,7 +24,105 @@ where
     }
 }
 
-/// Deserialize Decimal from JSON number (f64/int) or string
+/// Deserialize a token amount in base units (1e6, USDC decimals) into a whole-unit Decimal
+///
+/// Accepts the same string or number forms as [`deserialize_number_from_string`].
+pub fn deserialize_token_amount<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let amt: u64 = deserialize_number_from_string(deserializer)?;
+    Ok(crate::orders::token_u64_to_decimal(
+        amt,
+        crate::orders::USDC_DECIMALS,
+    ))
+}
+
+/// Timestamps below this are taken as seconds by [`deserialize_timestamp_flexible`]
+///
+/// 10^12 milliseconds is September 2001, while 10^12 seconds is tens of
+/// thousands of years away, so the two units can't be confused.
+const MILLIS_THRESHOLD: u64 = 1_000_000_000_000;
+
+/// Deserialize a timestamp from a string or integer into Unix milliseconds
+///
+/// WebSocket messages send timestamps as strings or integers, depending on
+/// the event type. Values that are in seconds rather than milliseconds are
+/// scaled up, so the result is always in milliseconds.
+pub fn deserialize_timestamp_flexible<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ts: u64 = deserialize_number_from_string(deserializer)?;
+    Ok(timestamp_millis(ts))
+}
+
+/// Optional variant of [`deserialize_timestamp_flexible`]
+///
+/// Empty strings and `null` are treated as None.
+pub fn deserialize_optional_timestamp_flexible<'de, D>(
+    deserializer: D,
+) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ts: Option<u64> = deserialize_optional_number_from_string(deserializer)?;
+    Ok(ts.map(timestamp_millis))
+}
+
+fn timestamp_millis(ts: u64) -> u64 {
+    if ts < MILLIS_THRESHOLD {
+        ts.saturating_mul(1000)
+    } else {
+        ts
+    }
+}
+
+/// Deserialize a value, treating `null` as the type's default
+pub fn deserialize_null_default<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + serde::Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Deserialize an optional number from a string, number, empty string, or null
+///
+/// Empty strings and `null` are treated as None.
+pub fn deserialize_optional_number_from_string<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + serde::Deserialize<'de>,
+    <T as FromStr>::Err: Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt<T> {
+        String(String),
+        Number(T),
+    }
+
+    match Option::<StringOrInt<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrInt::String(s)) if s.trim().is_empty() => Ok(None),
+        Some(StringOrInt::String(s)) => s
+            .trim()
+            .parse::<T>()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        Some(StringOrInt::Number(i)) => Ok(Some(i)),
+    }
+}
+
+/// Deserialize Decimal from JSON number (integer or float) or string
+///
+/// Numbers are parsed from their textual representation rather than through
+/// `f64`, so values such as `0.1` keep their exact decimal form. Scientific
+/// notation (e.g. `"1e-5"`) is accepted in both strings and numbers. `null`
+/// is rejected; use `Option<Decimal>` with `#[serde(default)]` for optional fields.
 pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
 where
     D: Deserializer<'de>,
@@ -34,19 +131,45 @@ where
     #[serde(untagged)]
     enum Repr {
         Str(String),
-        F64(f64),
-        U64(u64),
-        I64(i64),
+        Num(serde_json::Number),
     }
 
-    match Repr::deserialize(deserializer)? {
-        Repr::Str(s) => Decimal::from_str(&s).map_err(serde::de::Error::custom),
-        Repr::F64(f) => {
-            Decimal::from_f64(f).ok_or_else(|| serde::de::Error::custom("invalid f64 for Decimal"))
-        }
-        Repr::U64(u) => Ok(Decimal::from(u)),
-        Repr::I64(i) => Ok(Decimal::from(i)),
+    let s = match Repr::deserialize(deserializer)? {
+        Repr::Str(s) => s,
+        Repr::Num(n) => n.to_string(),
+    };
+    parse_decimal(s.trim()).map_err(serde::de::Error::custom)
+}
+
+/// Deserialize Option<Decimal> from JSON number, string, empty string, or null
+///
+/// Empty strings and `null` are treated as None.
+pub fn deserialize_optional_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Str(String),
+        Num(serde_json::Number),
     }
+
+    let s = match Option::<Repr>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(Repr::Str(s)) => s,
+        Some(Repr::Num(n)) => n.to_string(),
+    };
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    parse_decimal(s).map(Some).map_err(serde::de::Error::custom)
+}
+
+/// Parse a Decimal from plain or scientific notation
+fn parse_decimal(s: &str) -> Result<Decimal, rust_decimal::Error> {
+    Decimal::from_str(s).or_else(|e| Decimal::from_scientific(s).map_err(|_| e))
 }
 
 /// Deserialize Option<DateTime<Utc>> from an optional datetime string
@@ -74,7 +197,7 @@ where
             }
 
             // Try RFC3339 first
-            if let Ok(dt) = DateTime::parse_from_rfc3339(&s) {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
                 return Ok(Some(dt.with_timezone(&Utc)));
             }
 
@@ -94,7 +217,7 @@ where
             }
 
             // Try date-only format: "2022-07-27"
-            if let Ok(date) = NaiveDate::parse_from_str(&s, "%Y-%m-%d") {
+            if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
                 let dt = date
                     .and_hms_opt(0, 0, 0)
                     .ok_or_else(|| serde::de::Error::custom("invalid date"))?
@@ -165,4 +288,129 @@ mod tests {
             "2025-10-23T00:00:00+00:00"
         );
     }
+
+    #[derive(Deserialize)]
+    struct DecimalStruct {
+        #[serde(deserialize_with = "deserialize_decimal")]
+        value: Decimal,
+    }
+
+    fn parse_decimal_json(json: &str) -> serde_json::Result<Decimal> {
+        serde_json::from_str::<DecimalStruct>(json).map(|s| s.value)
+    }
+
+    #[test]
+    fn test_deserialize_decimal_from_string() {
+        assert_eq!(
+            parse_decimal_json(r#"{"value": "0.5"}"#).unwrap(),
+            Decimal::new(5, 1)
+        );
+        assert_eq!(
+            parse_decimal_json(r#"{"value": "0"}"#).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_deserialize_decimal_from_number() {
+        assert_eq!(
+            parse_decimal_json(r#"{"value": 0.5}"#).unwrap(),
+            Decimal::new(5, 1)
+        );
+        assert_eq!(
+            parse_decimal_json(r#"{"value": 0.1}"#).unwrap().to_string(),
+            "0.1"
+        );
+        assert_eq!(
+            parse_decimal_json(r#"{"value": 100}"#).unwrap(),
+            Decimal::from(100)
+        );
+        assert_eq!(
+            parse_decimal_json(r#"{"value": -3}"#).unwrap(),
+            Decimal::from(-3)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_decimal_scientific() {
+        assert_eq!(
+            parse_decimal_json(r#"{"value": 1e-5}"#).unwrap(),
+            Decimal::new(1, 5)
+        );
+        assert_eq!(
+            parse_decimal_json(r#"{"value": "1e-5"}"#).unwrap(),
+            Decimal::new(1, 5)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_decimal_null_is_error() {
+        assert!(parse_decimal_json(r#"{"value": null}"#).is_err());
+        assert!(parse_decimal_json(r#"{"value": "abc"}"#).is_err());
+    }
+
+    #[derive(Deserialize)]
+    struct OptionalDecimalStruct {
+        #[serde(default, deserialize_with = "deserialize_optional_decimal")]
+        value: Option<Decimal>,
+    }
+
+    #[test]
+    fn test_deserialize_optional_decimal() {
+        let parse = |json: &str| {
+            serde_json::from_str::<OptionalDecimalStruct>(json)
+                .unwrap()
+                .value
+        };
+        assert_eq!(parse(r#"{"value": "0.5"}"#), Some(Decimal::new(5, 1)));
+        assert_eq!(parse(r#"{"value": 0.5}"#), Some(Decimal::new(5, 1)));
+        assert_eq!(parse(r#"{"value": ""}"#), None);
+        assert_eq!(parse(r#"{"value": null}"#), None);
+        assert_eq!(parse(r#"{}"#), None);
+    }
+
+    #[derive(Deserialize)]
+    struct TokenAmountStruct {
+        #[serde(deserialize_with = "deserialize_token_amount")]
+        value: Decimal,
+    }
+
+    #[test]
+    fn test_deserialize_token_amount() {
+        let parsed: TokenAmountStruct = serde_json::from_str(r#"{"value": "2500000"}"#).unwrap();
+        assert_eq!(parsed.value, Decimal::new(25, 1));
+        let parsed: TokenAmountStruct = serde_json::from_str(r#"{"value": 1}"#).unwrap();
+        assert_eq!(parsed.value, Decimal::new(1, 6));
+        assert!(serde_json::from_str::<TokenAmountStruct>(r#"{"value": "-1"}"#).is_err());
+    }
+
+    #[derive(Deserialize)]
+    struct TimestampStruct {
+        #[serde(deserialize_with = "deserialize_timestamp_flexible")]
+        ts: u64,
+        #[serde(default, deserialize_with = "deserialize_optional_timestamp_flexible")]
+        optional: Option<u64>,
+    }
+
+    #[test]
+    fn test_deserialize_timestamp_flexible() {
+        let parse = |json: &str| serde_json::from_str::<TimestampStruct>(json).unwrap();
+        assert_eq!(parse(r#"{"ts": "1757908892351"}"#).ts, 1757908892351);
+        assert_eq!(parse(r#"{"ts": 1757908892351}"#).ts, 1757908892351);
+        // Seconds are scaled to milliseconds
+        assert_eq!(parse(r#"{"ts": "1757908892"}"#).ts, 1757908892000);
+        assert_eq!(parse(r#"{"ts": 1757908892}"#).ts, 1757908892000);
+
+        assert_eq!(
+            parse(r#"{"ts": 1, "optional": 1757908892351}"#).optional,
+            Some(1757908892351)
+        );
+        assert_eq!(
+            parse(r#"{"ts": 1, "optional": "1757908892351"}"#).optional,
+            Some(1757908892351)
+        );
+        assert_eq!(parse(r#"{"ts": 1, "optional": ""}"#).optional, None);
+        assert_eq!(parse(r#"{"ts": 1}"#).optional, None);
+        assert!(serde_json::from_str::<TimestampStruct>(r#"{"ts": "soon"}"#).is_err());
+    }
 }
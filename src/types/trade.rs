@@ -1,6 +1,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::request::Cursor;
 use crate::{types::ActivityType, Side};
 
 /// Custom deserializer for optional Side that treats empty strings as None
@@ -167,6 +168,95 @@ pub struct Activity {
     pub name: String,
 }
 
+/// Fields shared by entries of the CLOB live activity feed
+///
+/// The `/live-activity/events` endpoint is not formally documented, so every
+/// field is optional and both snake_case and camelCase names are accepted.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEventData {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default, alias = "conditionId", alias = "market")]
+    pub condition_id: Option<String>,
+    #[serde(default, alias = "assetId", alias = "asset")]
+    pub asset_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_side")]
+    pub side: Option<Side>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub price: Option<Decimal>,
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal"
+    )]
+    pub size: Option<Decimal>,
+    #[serde(default)]
+    pub outcome: Option<String>,
+    /// Unix timestamp (seconds)
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub timestamp: Option<u64>,
+    #[serde(default, alias = "proxyWallet", alias = "user")]
+    pub proxy_wallet: Option<String>,
+    #[serde(default, alias = "transactionHash")]
+    pub transaction_hash: Option<String>,
+}
+
+/// Entry of the CLOB live activity feed
+///
+/// The variant is chosen from the entry's `type` (or `event_type`) field,
+/// case-insensitively. Entries of any other type, or known types that don't
+/// match [`ActivityEventData`], are kept as [`ActivityEvent::Other`] so new
+/// activity kinds don't break the feed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ActivityEvent {
+    Trade(ActivityEventData),
+    Order(ActivityEventData),
+    Conversion(ActivityEventData),
+    Other(serde_json::Value),
+}
+
+impl ActivityEvent {
+    /// Shared fields, or `None` for [`ActivityEvent::Other`]
+    pub fn data(&self) -> Option<&ActivityEventData> {
+        match self {
+            ActivityEvent::Trade(data)
+            | ActivityEvent::Order(data)
+            | ActivityEvent::Conversion(data) => Some(data),
+            ActivityEvent::Other(_) => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ActivityEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("type")
+            .or_else(|| value.get("event_type"))
+            .and_then(|t| t.as_str())
+            .map(|t| t.to_ascii_uppercase());
+        let variant: fn(ActivityEventData) -> ActivityEvent = match kind.as_deref() {
+            Some("TRADE") => ActivityEvent::Trade,
+            Some("ORDER") => ActivityEvent::Order,
+            Some("CONVERSION") => ActivityEvent::Conversion,
+            _ => return Ok(ActivityEvent::Other(value)),
+        };
+        Ok(match ActivityEventData::deserialize(&value) {
+            Ok(data) => variant(data),
+            Err(_) => ActivityEvent::Other(value),
+        })
+    }
+}
+
 /// Closed position information from the data API
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ClosedPosition {
@@ -221,6 +311,8 @@ pub struct TradeParams {
     pub asset_id: Option<String>,
     pub before: Option<u64>,
     pub after: Option<u64>,
+    /// Pagination cursor (see [`END_CURSOR`](crate::request::END_CURSOR))
+    pub next_cursor: Option<String>,
 }
 
 impl TradeParams {
@@ -258,8 +350,13 @@ impl TradeParams {
         self
     }
 
+    pub fn next_cursor(mut self, next_cursor: impl Into<String>) -> Self {
+        self.next_cursor = Some(next_cursor.into());
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(&str, String)> {
-        let mut params = Vec::with_capacity(6);
+        let mut params = Vec::with_capacity(7);
 
         if let Some(ref id) = self.id {
             params.push(("id", id.clone()));
@@ -285,6 +382,165 @@ impl TradeParams {
             params.push(("maker_address", maker_address.clone()));
         }
 
+        if let Some(ref next_cursor) = self.next_cursor {
+            params.push(("next_cursor", next_cursor.clone()));
+        }
+
         params
     }
 }
+
+/// Trade from the CLOB trade history (`/data/trades`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClobTrade {
+    pub id: String,
+    #[serde(default)]
+    pub taker_order_id: String,
+    pub market: String,
+    pub asset_id: String,
+    pub side: Side,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub fee_rate_bps: Decimal,
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
+    pub price: Decimal,
+    pub status: String,
+    /// Match time (unix seconds)
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_number_from_string")]
+    pub match_time: u64,
+    #[serde(default)]
+    pub outcome: String,
+    #[serde(default)]
+    pub owner: String,
+    #[serde(default)]
+    pub maker_address: String,
+    #[serde(default)]
+    pub transaction_hash: String,
+    /// Whether the user was the MAKER or TAKER in this trade
+    #[serde(default)]
+    pub trader_side: Option<String>,
+}
+
+/// Paginated CLOB trade history response
+#[derive(Debug, Deserialize)]
+pub struct ClobTradesResponse {
+    #[serde(default)]
+    pub limit: u64,
+    #[serde(default)]
+    pub count: u64,
+    pub next_cursor: Option<String>,
+    pub data: Vec<ClobTrade>,
+}
+
+impl ClobTradesResponse {
+    /// Cursor of the next page, or None on the last page
+    pub fn next_cursor(&self) -> Option<Cursor> {
+        Cursor::next(self.next_cursor.as_deref())
+    }
+}
+
+/// Output format for trade exports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+}
+
+impl ClobTrade {
+    /// Column names of [`ExportFormat::Csv`] exports, in order
+    pub const EXPORT_COLUMNS: [&'static str; 9] = [
+        "timestamp",
+        "id",
+        "market",
+        "asset_id",
+        "side",
+        "price",
+        "size",
+        "fee_rate_bps",
+        "status",
+    ];
+
+    /// Write this trade as one export record (CSV row or JSON line)
+    pub fn write_export<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        format: ExportFormat,
+    ) -> std::io::Result<()> {
+        let values = [
+            self.match_time.to_string(),
+            self.id.clone(),
+            self.market.clone(),
+            self.asset_id.clone(),
+            self.side.as_str().to_string(),
+            self.price.to_string(),
+            self.size.to_string(),
+            self.fee_rate_bps.to_string(),
+            self.status.clone(),
+        ];
+
+        match format {
+            ExportFormat::Csv => {
+                let row: Vec<String> = values.iter().map(|v| csv_escape(v)).collect();
+                writeln!(writer, "{}", row.join(","))
+            }
+            ExportFormat::Jsonl => {
+                let record: serde_json::Map<String, serde_json::Value> = Self::EXPORT_COLUMNS
+                    .iter()
+                    .zip(values)
+                    .map(|(key, value)| (key.to_string(), serde_json::Value::String(value)))
+                    .collect();
+                writeln!(writer, "{}", serde_json::Value::Object(record))
+            }
+        }
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_activity_event_variants() {
+        let events: Vec<ActivityEvent> = serde_json::from_str(
+            r#"[
+                {"type": "TRADE", "conditionId": "0xc", "asset": "1", "side": "BUY",
+                 "price": "0.5", "size": 10, "timestamp": "1700000000"},
+                {"event_type": "order", "market": "0xc", "side": "SELL", "price": 0.6},
+                {"type": "CONVERSION", "proxyWallet": "0xw", "size": "3"},
+                {"type": "COMMENT", "body": "hello"},
+                {"type": "TRADE", "price": "not a number"}
+            ]"#,
+        )
+        .unwrap();
+
+        match &events[0] {
+            ActivityEvent::Trade(trade) => {
+                assert_eq!(trade.condition_id.as_deref(), Some("0xc"));
+                assert_eq!(trade.side, Some(Side::Buy));
+                assert_eq!(trade.price, Some(dec!(0.5)));
+                assert_eq!(trade.size, Some(dec!(10)));
+                assert_eq!(trade.timestamp, Some(1700000000));
+            }
+            other => panic!("expected trade, got {:?}", other),
+        }
+        assert!(matches!(&events[1], ActivityEvent::Order(o) if o.side == Some(Side::Sell)));
+        assert!(matches!(&events[2], ActivityEvent::Conversion(_)));
+        assert!(matches!(&events[3], ActivityEvent::Other(v) if v["body"] == "hello"));
+        // Known type that doesn't parse is kept rather than failing the feed
+        assert!(matches!(&events[4], ActivityEvent::Other(_)));
+        assert!(events[4].data().is_none());
+    }
+}
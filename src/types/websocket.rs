@@ -2,7 +2,7 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 use super::order::PriceLevel;
-use super::Side;
+use super::{OrderStatus, Side};
 
 // ============================================================================
 // Market WebSocket Events
@@ -31,8 +31,9 @@ pub struct BookEvent {
     pub market: String,
     /// Token/Asset ID
     pub asset_id: String,
-    /// Timestamp of the event
-    pub timestamp: String,
+    /// Timestamp of the event (Unix milliseconds)
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp_flexible")]
+    pub timestamp: u64,
     /// Hash of the order book
     pub hash: String,
     /// Buy side order book
@@ -51,9 +52,13 @@ pub struct PriceChangeEvent {
     pub event_type: String,
     /// Market ID
     pub market: String,
-    /// Timestamp (optional)
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    /// Timestamp (Unix milliseconds, optional)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp_flexible"
+    )]
+    pub timestamp: Option<u64>,
     /// Hash (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hash: Option<String>,
@@ -74,6 +79,38 @@ pub struct PriceChange {
     /// New size at this price level (0 means remove the level)
     #[serde(with = "rust_decimal::serde::str")]
     pub size: Decimal,
+    /// Hash of the asset's order book after this change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Best bid of the asset after this change
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub best_bid: Option<Decimal>,
+    /// Best ask of the asset after this change
+    #[serde(
+        default,
+        deserialize_with = "super::serde_helpers::deserialize_optional_decimal",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub best_ask: Option<Decimal>,
+}
+
+impl PriceChange {
+    /// The changed level's new price and size
+    pub fn level(&self) -> PriceLevel {
+        PriceLevel {
+            price: self.price,
+            size: self.size,
+        }
+    }
+
+    /// Whether the change removes the level; see [`PriceLevel::is_removal`]
+    pub fn is_removal(&self) -> bool {
+        self.level().is_removal()
+    }
 }
 
 /// Last trade price event
@@ -96,8 +133,9 @@ pub struct LastTradePriceEvent {
     pub fee_rate_bps: Decimal,
     /// Side of the trade (BUY or SELL)
     pub side: Side,
-    /// Timestamp of the trade
-    pub timestamp: String,
+    /// Timestamp of the trade (Unix milliseconds)
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp_flexible")]
+    pub timestamp: u64,
     /// Transaction hash on blockchain
     pub transaction_hash: String,
 }
@@ -117,8 +155,9 @@ pub struct TickSizeChangeEvent {
     /// New tick size
     #[serde(with = "rust_decimal::serde::str")]
     pub new_tick_size: Decimal,
-    /// Timestamp of the change
-    pub timestamp: String,
+    /// Timestamp of the change (Unix milliseconds)
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_timestamp_flexible")]
+    pub timestamp: u64,
 }
 
 // ============================================================================
@@ -128,6 +167,7 @@ pub struct TickSizeChangeEvent {
 /// Websocket event from the authenticated user stream
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 pub enum UserWsEvent {
     /// Trade execution event
     Trade(TradeEvent),
@@ -220,13 +260,13 @@ pub struct OrderEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order_owner: Option<String>,
     /// Original order size
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub original_size: Decimal,
     /// Amount that has been matched
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub size_matched: Decimal,
     /// Order price
-    #[serde(with = "rust_decimal::serde::str")]
+    #[serde(deserialize_with = "super::serde_helpers::deserialize_decimal")]
     pub price: Decimal,
     /// Associated trades (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -236,21 +276,33 @@ pub struct OrderEvent {
     /// Order event type (PLACEMENT, CANCELLATION, etc.)
     #[serde(rename = "type")]
     pub order_event_type: String,
-    /// Created at timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub created_at: Option<String>,
-    /// Expiration timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub expiration: Option<String>,
+    /// Creation time (unix seconds)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub created_at: Option<u64>,
+    /// Expiration time (unix seconds, 0 for orders that don't expire)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_number_from_string"
+    )]
+    pub expiration: Option<u64>,
     /// Order type (GTC, FOK, etc.)
     pub order_type: String,
-    /// Order status (LIVE, MATCHED, CANCELLED, etc.)
-    pub status: String,
+    /// Order status (LIVE, MATCHED, CANCELED, etc.)
+    pub status: OrderStatus,
     /// Maker address
     pub maker_address: String,
-    /// Event timestamp
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub timestamp: Option<String>,
+    /// Event time (Unix milliseconds)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "super::serde_helpers::deserialize_optional_timestamp_flexible"
+    )]
+    pub timestamp: Option<u64>,
 }
 
 // ============================================================================
@@ -299,3 +351,112 @@ impl UserAuthentication {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    const ORDER_MESSAGE: &str = r#"{
+        "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+        "associate_trades": null,
+        "created_at": "1672290687",
+        "event_type": "order",
+        "expiration": "0",
+        "id": "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b",
+        "maker_address": "0x3b0a5bd15f8a9e3a4e2e2ae6e1ef4b1b5e0e1c9b",
+        "market": "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af",
+        "order_owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+        "order_type": "GTC",
+        "original_size": "10",
+        "outcome": "YES",
+        "owner": "9180014b-33c8-9240-a14b-bdca11c0a465",
+        "price": "0.57",
+        "side": "SELL",
+        "size_matched": "4.5",
+        "status": "LIVE",
+        "timestamp": "1672290701",
+        "type": "UPDATE"
+    }"#;
+
+    #[test]
+    fn test_order_event_fixture() {
+        let order: OrderEvent = serde_json::from_str(ORDER_MESSAGE).unwrap();
+        assert_eq!(order.price, dec!(0.57));
+        assert_eq!(order.original_size, dec!(10));
+        assert_eq!(order.size_matched, dec!(4.5));
+        assert_eq!(order.original_size - order.size_matched, dec!(5.5));
+        assert_eq!(order.created_at, Some(1672290687));
+        assert_eq!(order.expiration, Some(0));
+        assert_eq!(order.timestamp, Some(1672290701000));
+        assert_eq!(order.status, OrderStatus::Live);
+
+        let event: UserWsEvent = serde_json::from_str(ORDER_MESSAGE).unwrap();
+        assert!(matches!(event, UserWsEvent::Order(_)));
+    }
+
+    #[test]
+    fn test_order_event_without_timestamps() {
+        let mut value: serde_json::Value = serde_json::from_str(ORDER_MESSAGE).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("created_at");
+        object.remove("timestamp");
+        object.insert("expiration".into(), "".into());
+
+        let order: OrderEvent = serde_json::from_value(value).unwrap();
+        assert_eq!(order.created_at, None);
+        assert_eq!(order.expiration, None);
+        assert_eq!(order.timestamp, None);
+    }
+
+    const PRICE_CHANGE_MESSAGE: &str = r#"{
+        "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+        "price_changes": [
+            {
+                "asset_id": "71321045679252212594626385532706912750332728571942532289631379312455583992563",
+                "price": "0.5",
+                "size": "200",
+                "side": "BUY",
+                "hash": "56621a121a47ed9333273e21c83b660cff37ae50",
+                "best_bid": "0.5",
+                "best_ask": "1"
+            },
+            {
+                "asset_id": "52114319501245915516055106046884209969926127482827954674443846427813813222426",
+                "price": "0.5",
+                "size": "200",
+                "side": "SELL",
+                "hash": "1895759e4df7a796bf4f1c5a5950b748306923e2",
+                "best_bid": "0",
+                "best_ask": "0.5"
+            }
+        ],
+        "timestamp": "1757908892351",
+        "event_type": "price_change"
+    }"#;
+
+    #[test]
+    fn test_price_change_event_fixture() {
+        let event: PriceChangeEvent = serde_json::from_str(PRICE_CHANGE_MESSAGE).unwrap();
+        assert_eq!(
+            event.market,
+            "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+        );
+        assert_eq!(event.timestamp, Some(1757908892351));
+        assert_eq!(event.price_changes.len(), 2);
+
+        let change = &event.price_changes[0];
+        assert_eq!(change.side, Side::Buy);
+        assert_eq!(change.size, dec!(200));
+        assert_eq!(
+            change.hash.as_deref(),
+            Some("56621a121a47ed9333273e21c83b660cff37ae50")
+        );
+        assert_eq!(change.best_bid, Some(dec!(0.5)));
+        assert_eq!(change.best_ask, Some(dec!(1)));
+        assert_eq!(event.price_changes[1].best_bid, Some(dec!(0)));
+
+        let event: WsEvent = serde_json::from_str(PRICE_CHANGE_MESSAGE).unwrap();
+        assert!(matches!(event, WsEvent::PriceChange(_)));
+    }
+}
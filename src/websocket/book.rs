@@ -0,0 +1,340 @@
+use futures_util::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+use crate::error::Result;
+use crate::types::{OrderBookSummary, PriceLevel, Side, WsEvent};
+
+/// Effect of [`OrderBook::set_level`] on the book
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelUpdate {
+    /// The price wasn't on the book and was added
+    Inserted,
+    /// The size at an existing price changed
+    Updated,
+    /// The price was removed
+    Removed,
+    /// The book already held this size, or the removed price was absent
+    Unchanged,
+}
+
+/// Order book of one asset maintained from market WebSocket events
+///
+/// `book` events replace the whole book and `price_change` events update
+/// single levels, a size of 0 removing the level. Events for other assets are
+/// ignored, so the book can be fed a stream subscribed to several tokens.
+///
+/// The book is empty until the first `book` snapshot arrives; check
+/// [`is_synced`](Self::is_synced) before relying on it. A book converted from
+/// a REST [`OrderBookSummary`] starts synced, e.g. from
+/// [`ClobClient::get_order_books_map`](crate::ClobClient::get_order_books_map).
+#[derive(Debug, Clone, Default)]
+pub struct OrderBook {
+    asset_id: String,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    hash: Option<String>,
+    timestamp: Option<u64>,
+    synced: bool,
+}
+
+impl OrderBook {
+    /// Empty book for `asset_id`
+    pub fn new(asset_id: impl Into<String>) -> Self {
+        Self {
+            asset_id: asset_id.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Apply a market event, returning whether it changed the book
+    pub fn apply(&mut self, event: &WsEvent) -> bool {
+        match event {
+            WsEvent::Book(book) if book.asset_id == self.asset_id => {
+                self.replace(&book.bids, &book.asks, book.hash.clone(), book.timestamp);
+                true
+            }
+            WsEvent::PriceChange(event) => {
+                let mut changed = false;
+                for change in event.price_changes.iter() {
+                    if change.asset_id != self.asset_id {
+                        continue;
+                    }
+                    self.set_level(change.side, &change.level());
+                    self.hash = change.hash.clone().or_else(|| event.hash.clone());
+                    changed = true;
+                }
+                if changed && event.timestamp.is_some() {
+                    self.timestamp = event.timestamp;
+                }
+                changed
+            }
+            _ => false,
+        }
+    }
+
+    /// Replace the whole book with a snapshot
+    fn replace(&mut self, bids: &[PriceLevel], asks: &[PriceLevel], hash: String, timestamp: u64) {
+        let levels = |levels: &[PriceLevel]| {
+            levels
+                .iter()
+                .filter(|l| !l.is_removal())
+                .map(|l| (l.price, l.size))
+                .collect()
+        };
+        self.bids = levels(bids);
+        self.asks = levels(asks);
+        self.hash = Some(hash);
+        self.timestamp = Some(timestamp);
+        self.synced = true;
+    }
+
+    /// Set the size at a price on one side of the book
+    ///
+    /// Follows the rule of book updates: a [removal](PriceLevel::is_removal)
+    /// deletes the price, any other size replaces it or inserts it if absent.
+    pub fn set_level(&mut self, side: Side, level: &PriceLevel) -> LevelUpdate {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+        if level.is_removal() {
+            match levels.remove(&level.price) {
+                Some(_) => LevelUpdate::Removed,
+                None => LevelUpdate::Unchanged,
+            }
+        } else {
+            match levels.insert(level.price, level.size) {
+                None => LevelUpdate::Inserted,
+                Some(size) if size == level.size => LevelUpdate::Unchanged,
+                Some(_) => LevelUpdate::Updated,
+            }
+        }
+    }
+
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// Whether a `book` snapshot has been applied
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Bids, best (highest) first
+    pub fn bids(&self) -> Vec<PriceLevel> {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(&price, &size)| PriceLevel { price, size })
+            .collect()
+    }
+
+    /// Asks, best (lowest) first
+    pub fn asks(&self) -> Vec<PriceLevel> {
+        self.asks
+            .iter()
+            .map(|(&price, &size)| PriceLevel { price, size })
+            .collect()
+    }
+
+    /// Highest bid price, or None if there are no bids
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.keys().next_back().copied()
+    }
+
+    /// Lowest ask price, or None if there are no asks
+    pub fn best_ask(&self) -> Option<Decimal> {
+        self.asks.keys().next().copied()
+    }
+
+    /// Hash sent with the last applied event
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    /// Timestamp of the last applied event that carried one, in Unix
+    /// milliseconds
+    pub fn timestamp(&self) -> Option<u64> {
+        self.timestamp
+    }
+}
+
+impl From<OrderBookSummary> for OrderBook {
+    fn from(summary: OrderBookSummary) -> Self {
+        let mut book = OrderBook::new(summary.asset_id);
+        book.replace(
+            &summary.bids,
+            &summary.asks,
+            summary.hash,
+            summary.timestamp,
+        );
+        book
+    }
+}
+
+/// Downsample a market event stream to book snapshots at a fixed cadence
+///
+/// Every event is applied to a local [`OrderBook`] for `asset_id` as it
+/// arrives, but the book is only yielded once per `interval`, starting one
+/// interval after the call. Ticks before the first `book` snapshot are
+/// skipped, as are ticks missed because the consumer was slow. Errors from
+/// `stream` are logged and skipped; the returned stream ends when `stream`
+/// does.
+///
+/// ```no_run
+/// use polymarket_rs::websocket::{throttled_book, MarketWsClient};
+/// use futures_util::StreamExt;
+/// use std::time::Duration;
+///
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// let token_id = "token_id".to_string();
+/// let events = MarketWsClient::new().subscribe(vec![token_id.clone()]).await?;
+/// let mut books = Box::pin(throttled_book(events, token_id, Duration::from_millis(500)));
+/// while let Some(book) = books.next().await {
+///     println!("{:?} / {:?}", book.best_bid(), book.best_ask());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `interval` is zero.
+pub fn throttled_book<S>(
+    stream: S,
+    asset_id: impl Into<String>,
+    interval: Duration,
+) -> impl Stream<Item = OrderBook>
+where
+    S: Stream<Item = Result<WsEvent>> + Unpin,
+{
+    let mut ticker = interval_at(Instant::now() + interval, interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let book = OrderBook::new(asset_id);
+
+    futures_util::stream::unfold(
+        (stream.fuse(), ticker, book),
+        |(mut stream, mut ticker, mut book)| async move {
+            loop {
+                tokio::select! {
+                    event = stream.next() => match event {
+                        Some(Ok(event)) => {
+                            book.apply(&event);
+                        }
+                        Some(Err(e)) => log::warn!("Skipping market stream error: {}", e),
+                        None => return None,
+                    },
+                    _ = ticker.tick() => {
+                        if book.is_synced() {
+                            let snapshot = book.clone();
+                            return Some((snapshot, (stream, ticker, book)));
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn book_event(bid: &str) -> WsEvent {
+        serde_json::from_str(&format!(
+            r#"{{"event_type": "book", "market": "0x1", "asset_id": "1",
+                "timestamp": "1757908892351", "hash": "h1",
+                "bids": [{{"price": "{}", "size": "10"}}],
+                "asks": [{{"price": "0.6", "size": "5"}}]}}"#,
+            bid
+        ))
+        .unwrap()
+    }
+
+    fn price_change(asset_id: &str, price: &str, size: &str) -> WsEvent {
+        serde_json::from_str(&format!(
+            r#"{{"event_type": "price_change", "market": "0x1", "timestamp": 1757908892352,
+                "price_changes": [{{"asset_id": "{}", "side": "SELL",
+                    "price": "{}", "size": "{}", "hash": "h2"}}]}}"#,
+            asset_id, price, size
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_order_book_applies_events() {
+        let mut book = OrderBook::new("1");
+        assert!(!book.apply(&price_change("2", "0.55", "1")));
+        assert!(book.apply(&book_event("0.4")));
+        assert!(book.is_synced());
+
+        assert!(book.apply(&price_change("1", "0.55", "3")));
+        assert_eq!(book.best_ask(), Some(dec!(0.55)));
+        assert_eq!(book.hash(), Some("h2"));
+        assert_eq!(book.timestamp(), Some(1757908892352));
+
+        book.apply(&price_change("1", "0.55", "0.00"));
+        assert_eq!(book.best_ask(), Some(dec!(0.6)));
+        assert_eq!(book.best_bid(), Some(dec!(0.4)));
+        assert_eq!(book.bids().len(), 1);
+    }
+
+    #[test]
+    fn test_set_level_transitions() {
+        let level = |price, size| PriceLevel { price, size };
+        let mut book = OrderBook::new("1");
+
+        assert_eq!(
+            book.set_level(Side::Buy, &level(dec!(0.5), dec!(10))),
+            LevelUpdate::Inserted
+        );
+        // Prices compare by value, whatever their scale
+        assert_eq!(
+            book.set_level(Side::Buy, &level(dec!(0.50), dec!(10.0))),
+            LevelUpdate::Unchanged
+        );
+        assert_eq!(
+            book.set_level(Side::Buy, &level(dec!(0.5), dec!(4))),
+            LevelUpdate::Updated
+        );
+        assert_eq!(book.bids()[0].size, dec!(4));
+        assert_eq!(
+            book.set_level(Side::Sell, &level(dec!(0.5), dec!(0))),
+            LevelUpdate::Unchanged
+        );
+        assert_eq!(
+            book.set_level(Side::Buy, &level(dec!(0.500), dec!(0.00))),
+            LevelUpdate::Removed
+        );
+        assert_eq!(book.best_bid(), None);
+
+        assert!(level(dec!(0.5), dec!(0.000)).is_removal());
+        assert!(!level(dec!(0.5), dec!(0.001)).is_removal());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_throttled_book_emits_latest_state_per_tick() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Result<WsEvent>>();
+        let events = Box::pin(futures_util::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (event, rx))
+        }));
+        let mut books = Box::pin(throttled_book(events, "1", Duration::from_millis(500)));
+
+        // Several updates within one interval are coalesced into one snapshot
+        tx.send(Ok(book_event("0.4"))).unwrap();
+        tx.send(Ok(book_event("0.45"))).unwrap();
+        let book = books.next().await.unwrap();
+        assert_eq!(book.best_bid(), Some(dec!(0.45)));
+
+        tx.send(Ok(price_change("1", "0.5", "1"))).unwrap();
+        let book = books.next().await.unwrap();
+        assert_eq!(book.best_ask(), Some(dec!(0.5)));
+
+        drop(tx);
+        assert!(books.next().await.is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+use crate::error::Error;
+
+/// Why the server closed a WebSocket connection, from the Close frame code
+///
+/// Polymarket doesn't document its close codes, so authentication failures
+/// are recognised by the conventional codes: 3000 / 4001 (unauthorized) and
+/// 3003 / 4003 (forbidden).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    /// 1000: the server finished with the connection
+    Normal,
+    /// 1001: the server is going down or the connection was idle too long
+    GoingAway,
+    /// 1008: a message broke the server's policy
+    PolicyViolation,
+    /// The API credentials were rejected
+    AuthFailed,
+    /// 1012: the server is restarting
+    ServerRestart,
+    /// Any other code
+    Other(u16),
+}
+
+impl CloseReason {
+    /// Whether reconnecting can succeed
+    ///
+    /// Authentication failures and policy violations will recur on a new
+    /// connection with the same credentials and subscription, so
+    /// [`ReconnectingStream`](super::ReconnectingStream) stops instead of
+    /// retrying them.
+    pub fn is_retryable(self) -> bool {
+        !matches!(self, CloseReason::AuthFailed | CloseReason::PolicyViolation)
+    }
+
+    /// Numeric close code; `AuthFailed` reports 4001
+    pub fn code(self) -> u16 {
+        match self {
+            CloseReason::Normal => 1000,
+            CloseReason::GoingAway => 1001,
+            CloseReason::PolicyViolation => 1008,
+            CloseReason::AuthFailed => 4001,
+            CloseReason::ServerRestart => 1012,
+            CloseReason::Other(code) => code,
+        }
+    }
+}
+
+impl From<u16> for CloseReason {
+    fn from(code: u16) -> Self {
+        match code {
+            1000 => CloseReason::Normal,
+            1001 => CloseReason::GoingAway,
+            1008 => CloseReason::PolicyViolation,
+            1012 => CloseReason::ServerRestart,
+            3000 | 3003 | 4001 | 4003 => CloseReason::AuthFailed,
+            code => CloseReason::Other(code),
+        }
+    }
+}
+
+impl fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CloseReason::Normal => write!(f, "normal closure"),
+            CloseReason::GoingAway => write!(f, "going away"),
+            CloseReason::PolicyViolation => write!(f, "policy violation"),
+            CloseReason::AuthFailed => write!(f, "authentication failed"),
+            CloseReason::ServerRestart => write!(f, "server restart"),
+            CloseReason::Other(code) => write!(f, "code {}", code),
+        }
+    }
+}
+
+/// Error for a Close frame received from the server
+pub(crate) fn close_error(frame: Option<CloseFrame<'_>>) -> Error {
+    match frame {
+        Some(frame) => Error::ServerClosed {
+            reason: u16::from(frame.code).into(),
+            message: frame.reason.into_owned(),
+        },
+        None => Error::ConnectionClosed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    #[test]
+    fn test_close_reason_from_code() {
+        assert_eq!(CloseReason::from(1000), CloseReason::Normal);
+        assert_eq!(CloseReason::from(1012), CloseReason::ServerRestart);
+        assert_eq!(CloseReason::from(4001), CloseReason::AuthFailed);
+        assert_eq!(CloseReason::from(4999), CloseReason::Other(4999));
+        assert!(CloseReason::GoingAway.is_retryable());
+        assert!(!CloseReason::AuthFailed.is_retryable());
+        assert!(!CloseReason::PolicyViolation.is_retryable());
+    }
+
+    #[test]
+    fn test_close_error() {
+        let frame = CloseFrame {
+            code: CloseCode::Policy,
+            reason: "invalid subscription".into(),
+        };
+        match close_error(Some(frame)) {
+            Error::ServerClosed { reason, message } => {
+                assert_eq!(reason, CloseReason::PolicyViolation);
+                assert_eq!(message, "invalid subscription");
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(matches!(close_error(None), Error::ConnectionClosed));
+    }
+}
@@ -0,0 +1,126 @@
+use futures_util::Stream;
+use serde::de::DeserializeOwned;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+
+use crate::error::{Error, Result};
+
+pub(crate) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Open a WebSocket connection, optionally bounding the handshake duration
+pub(crate) async fn connect(url: &str, connect_timeout: Option<Duration>) -> Result<WsStream> {
+    let (ws_stream, _) = match connect_timeout {
+        Some(timeout) => tokio::time::timeout(timeout, connect_async(url))
+            .await
+            .map_err(|_| {
+                Error::Timeout(format!(
+                    "WebSocket handshake with {} did not complete within {:?}",
+                    url, timeout
+                ))
+            })??,
+        None => connect_async(url).await?,
+    };
+    Ok(ws_stream)
+}
+
+/// Parse a text frame holding either a single event or an array of events
+///
+/// Every element of an array is parsed and returned in order, so batched
+/// updates are never dropped. An empty array yields nothing.
+pub(crate) fn parse_text_events<T: DeserializeOwned>(text: &str) -> Vec<Result<T>> {
+    match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(serde_json::Value::Array(events)) => events
+            .into_iter()
+            .map(|event| serde_json::from_value(event).map_err(Error::Json))
+            .collect(),
+        Ok(event) => vec![serde_json::from_value(event).map_err(Error::Json)],
+        Err(e) => vec![Err(Error::Json(e))],
+    }
+}
+
+/// WebSocket connection that sends a Close frame when dropped
+///
+/// Yields raw messages like the underlying stream. Keeping both halves of the
+/// connection together lets the drop guard close the socket politely instead
+/// of leaving the server with a dangling connection.
+pub(crate) struct ClosingStream {
+    inner: Option<WsStream>,
+}
+
+impl ClosingStream {
+    pub(crate) fn new(inner: WsStream) -> Self {
+        Self { inner: Some(inner) }
+    }
+}
+
+impl Stream for ClosingStream {
+    type Item = std::result::Result<Message, tungstenite::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.inner.as_mut() {
+            Some(inner) => Pin::new(inner).poll_next(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+impl Drop for ClosingStream {
+    fn drop(&mut self) {
+        let Some(mut inner) = self.inner.take() else {
+            return;
+        };
+        // Sending the Close frame is async, so hand it to the runtime if there is one
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                let _ = inner.close(None).await;
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_connect_timeout() {
+        // Accept TCP connections but never answer the WebSocket handshake
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let url = format!("ws://{}", addr);
+        let result = connect(&url, Some(Duration::from_millis(100))).await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_drop_sends_close_frame() {
+        use futures_util::StreamExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await
+        });
+
+        let ws = connect(&format!("ws://{}", addr), None).await.unwrap();
+        drop(ClosingStream::new(ws));
+
+        let received = tokio::time::timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(matches!(received, Some(Ok(Message::Close(_)))));
+    }
+}
@@ -1,11 +1,40 @@
 use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+use tokio_tungstenite::tungstenite::Message;
 
+use super::connection::{connect, parse_text_events, ClosingStream};
 use crate::error::{Error, Result};
-use crate::types::{MarketSubscription, WsEvent};
+use crate::types::{LastTradePriceEvent, MarketSubscription, PriceChangeEvent, WsEvent};
+
+/// Stream of order book events ([`WsEvent::Book`], [`WsEvent::PriceChange`],
+/// [`WsEvent::TickSizeChange`]) returned by [`MarketWsClient::subscribe_split`]
+pub type BookStream = Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>;
+
+/// Stream of trade prints returned by [`MarketWsClient::subscribe_split`]
+pub type TradeStream = Pin<Box<dyn Stream<Item = Result<LastTradePriceEvent>> + Send>>;
+
+/// Stream of order book deltas returned by [`MarketWsClient::subscribe_price_changes`]
+pub type PriceChangeStream = Pin<Box<dyn Stream<Item = Result<PriceChangeEvent>> + Send>>;
+
+/// Callback receiving raw text frames, see [`MarketWsClient::with_raw_tap`]
+#[derive(Clone)]
+struct RawTap(Arc<dyn Fn(&str) + Send + Sync>);
+
+impl std::fmt::Debug for RawTap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RawTap")
+    }
+}
+
+/// Turn a channel receiver into a stream that ends when all senders are dropped
+fn receiver_stream<T: Send + 'static>(rx: mpsc::UnboundedReceiver<T>) -> impl Stream<Item = T> {
+    futures_util::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|item| (item, rx))
+    })
+}
 
 /// Handle for querying WebSocket subscription state
 ///
@@ -41,80 +70,73 @@ impl SubscriptionHandle {
 /// For Rust, the recommended approach is to use [`ReconnectingStream`](crate::websocket::ReconnectingStream)
 /// which automatically handles connection resets and reconnects with exponential backoff.
 /// This is more robust than manual ping/pong management.
+///
+/// Dropping a returned stream sends a Close frame to the server before the
+/// connection is torn down, so frequent re-subscribing doesn't leave dangling
+/// connections behind.
 #[derive(Debug, Clone)]
 pub struct MarketWsClient {
     ws_url: String,
+    connect_timeout: Option<Duration>,
+    raw_tap: Option<RawTap>,
 }
 
-/// Parse a WebSocket message into a WsEvent
+/// Parse a WebSocket message into WsEvents
 ///
 /// This is a helper function that handles the parsing logic shared by both
-/// subscribe() and subscribe_with_handle() methods.
+/// subscribe() and subscribe_with_handle() methods. A message carrying an
+/// array of events yields all of them, in order.
 fn parse_ws_message(
     msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
-) -> Option<Result<WsEvent>> {
+) -> Vec<Result<WsEvent>> {
     match msg {
         Ok(Message::Text(text)) => {
+            #[cfg(feature = "metrics")]
+            crate::metrics::record(|m| m.message_received("market"));
+
             // Skip empty or whitespace-only messages
             let trimmed = text.trim();
             if trimmed.is_empty() {
-                return None;
+                return Vec::new();
             }
 
             // Skip PING/PONG messages sent as text (some servers do this)
             if trimmed.eq_ignore_ascii_case("ping") || trimmed.eq_ignore_ascii_case("pong") {
-                return None;
+                return Vec::new();
             }
 
             // The server can send either a single object or an array
-            // Try to parse as array first
-            if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                // Got an array, take the first event
-                if let Some(first) = events.first() {
-                    match serde_json::from_value::<WsEvent>(first.clone()) {
-                        Ok(event) => return Some(Ok(event)),
-                        Err(e) => return Some(Err(Error::Json(e))),
-                    }
-                } else {
-                    // Empty array, ignore
-                    return None;
-                }
-            }
-
-            // Try parsing as single object
-            match serde_json::from_str::<WsEvent>(&text) {
-                Ok(event) => Some(Ok(event)),
-                Err(e) => {
-                    // Log unexpected message format for debugging
-                    log::warn!(
-                        "Unexpected WebSocket message (first 200 chars): {}",
-                        &text.chars().take(200).collect::<String>()
-                    );
-                    Some(Err(Error::Json(e)))
-                }
+            let events = parse_text_events(&text);
+            if events.iter().any(|e| e.is_err()) {
+                // Log unexpected message format for debugging
+                log::warn!(
+                    "Unexpected WebSocket message (first 200 chars): {}",
+                    &text.chars().take(200).collect::<String>()
+                );
             }
+            events
         }
         Ok(Message::Close(_)) => {
             // Connection closed gracefully
-            Some(Err(Error::ConnectionClosed))
+            vec![Err(Error::ConnectionClosed)]
         }
         Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
             // Ignore ping/pong frames (handled automatically)
-            None
+            Vec::new()
         }
         Ok(Message::Binary(_)) => {
             // Unexpected binary message
-            Some(Err(Error::WebSocket(
+            vec![Err(Error::WebSocket(
                 "Unexpected binary message".to_string(),
-            )))
+            ))]
         }
         Ok(Message::Frame(_)) => {
             // Raw frame (shouldn't happen)
-            None
+            Vec::new()
         }
         Err(e) => {
             // WebSocket error
-            Some(Err(Error::WebSocket(e.to_string())))
+            vec![Err(Error::WebSocket(e.to_string()))]
         }
     }
 }
@@ -123,10 +145,15 @@ impl MarketWsClient {
     /// Default WebSocket URL for market data
     const DEFAULT_WS_URL: &'static str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
 
+    /// Recommended number of tokens per connection for [`subscribe_chunked`](Self::subscribe_chunked)
+    pub const RECOMMENDED_CHUNK_SIZE: usize = 100;
+
     /// Create a new market WebSocket client with the default endpoint
     pub fn new() -> Self {
         Self {
             ws_url: Self::DEFAULT_WS_URL.to_string(),
+            connect_timeout: None,
+            raw_tap: None,
         }
     }
 
@@ -134,9 +161,50 @@ impl MarketWsClient {
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            connect_timeout: None,
+            raw_tap: None,
         }
     }
 
+    /// Set a timeout for the WebSocket handshake
+    ///
+    /// If the connection is not established within `timeout`, subscribing
+    /// fails with [`Error::Timeout`]. By default there is no timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Call `tap` with the text of every frame received, before it is parsed
+    ///
+    /// Meant for protocol debugging: capture the exact messages behind an
+    /// [`Error::Json`] or an unexpected event to report or replay them. The
+    /// tap sees every text frame of every connection opened by this client,
+    /// including ones that fail to parse, and runs inline on the stream, so
+    /// keep it cheap. Off by default.
+    ///
+    /// ```no_run
+    /// use polymarket_rs::websocket::MarketWsClient;
+    ///
+    /// let client = MarketWsClient::new().with_raw_tap(|text| eprintln!("raw: {}", text));
+    /// ```
+    pub fn with_raw_tap(mut self, tap: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.raw_tap = Some(RawTap(Arc::new(tap)));
+        self
+    }
+
+    /// Parse the frames of a connection into events, passing text frames to
+    /// the raw tap first
+    fn events(&self, read: ClosingStream) -> impl Stream<Item = Result<WsEvent>> + Send {
+        let tap = self.raw_tap.clone();
+        read.flat_map(move |msg| {
+            if let (Some(tap), Ok(Message::Text(text))) = (&tap, &msg) {
+                (tap.0)(text);
+            }
+            futures_util::stream::iter(parse_ws_message(msg))
+        })
+    }
+
     /// Subscribe to market updates with a handle to query subscription state
     ///
     /// Returns a stream of [`WsEvent`] items and a [`SubscriptionHandle`] that can be used
@@ -166,6 +234,7 @@ impl MarketWsClient {
     ///
     /// Returns an error if:
     /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
     /// - The subscription message cannot be sent
     pub async fn subscribe_with_handle(
         &self,
@@ -175,10 +244,7 @@ impl MarketWsClient {
         SubscriptionHandle,
     )> {
         // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
+        let mut ws_stream = connect(&self.ws_url, self.connect_timeout).await?;
 
         // Create subscription message
         let subscription = MarketSubscription {
@@ -188,13 +254,13 @@ impl MarketWsClient {
         let subscription_msg = serde_json::to_string(&subscription)?;
 
         // Send initial subscription message
-        write
+        ws_stream
             .send(Message::Text(subscription_msg))
             .await
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        // Keep the write half alive so dropping the stream sends a Close frame
+        let read = ClosingStream::new(ws_stream);
 
         // Create shared state for current tokens
         let current_tokens = Arc::new(RwLock::new(token_ids));
@@ -203,7 +269,7 @@ impl MarketWsClient {
         let handle = SubscriptionHandle { current_tokens };
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        let stream = self.events(read);
 
         Ok((Box::pin(stream), handle))
     }
@@ -232,16 +298,14 @@ impl MarketWsClient {
     ///
     /// Returns an error if:
     /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
     /// - The subscription message cannot be sent
     pub async fn subscribe(
         &self,
         token_ids: Vec<String>,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
         // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (write, read) = ws_stream.split();
-        let mut write = write;
+        let mut ws_stream = connect(&self.ws_url, self.connect_timeout).await?;
 
         // Create subscription message
         let subscription = MarketSubscription {
@@ -251,19 +315,197 @@ impl MarketWsClient {
         let subscription_msg = serde_json::to_string(&subscription)?;
 
         // Send subscription message
-        write
+        ws_stream
             .send(Message::Text(subscription_msg))
             .await
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
-        // Drop the write half since we don't need to send any more messages
-        drop(write);
+        // Keep the write half alive so dropping the stream sends a Close frame
+        let read = ClosingStream::new(ws_stream);
 
         // Return stream that parses events using the shared helper function
-        let stream = read.filter_map(|msg| async move { parse_ws_message(msg) });
+        let stream = self.events(read);
 
         Ok(Box::pin(stream))
     }
+
+    /// Subscribe and wait until the initial order book snapshot arrives
+    ///
+    /// Works like [`subscribe`](Self::subscribe), but only resolves once the
+    /// first [`WsEvent::Book`] is received, confirming the subscription is live.
+    /// The returned stream starts with that snapshot, preceded by any other
+    /// events that arrived before it.
+    ///
+    /// `timeout` bounds the wait for the snapshot after subscribing; the
+    /// handshake itself is bounded by [`with_connect_timeout`](Self::with_connect_timeout).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Subscribing fails (see [`subscribe`](Self::subscribe))
+    /// - No book snapshot arrives within `timeout` ([`Error::Timeout`])
+    /// - The connection yields an error or closes before the snapshot arrives
+    pub async fn subscribe_confirmed(
+        &self,
+        token_ids: Vec<String>,
+        timeout: Duration,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        let mut stream = self.subscribe(token_ids).await?;
+
+        let wait_for_book = async {
+            let mut received = Vec::new();
+            while let Some(item) = stream.next().await {
+                let event = item?;
+                let is_book = matches!(event, WsEvent::Book(_));
+                received.push(Ok(event));
+                if is_book {
+                    return Ok(received);
+                }
+            }
+            Err(Error::ConnectionClosed)
+        };
+        let received = tokio::time::timeout(timeout, wait_for_book)
+            .await
+            .map_err(|_| {
+                Error::Timeout(format!(
+                    "no order book snapshot received within {:?}",
+                    timeout
+                ))
+            })??;
+
+        Ok(Box::pin(futures_util::stream::iter(received).chain(stream)))
+    }
+
+    /// Subscribe to many tokens by spreading them over several connections
+    ///
+    /// `token_ids` is split into chunks of at most `chunk_size` tokens, each
+    /// subscribed on its own connection, and the events of all connections
+    /// are merged into one stream in arrival order. All connections are opened
+    /// concurrently; if any of them fails, the others are dropped and the error
+    /// is returned.
+    ///
+    /// Polymarket does not publish a per-connection token limit, but very large
+    /// subscriptions tend to be dropped or throttled.
+    /// [`RECOMMENDED_CHUNK_SIZE`](Self::RECOMMENDED_CHUNK_SIZE) (100) is a
+    /// conservative default that keeps the connection count low for a few
+    /// hundred tokens.
+    ///
+    /// Each connection reports its own [`Error::ConnectionClosed`] when it
+    /// closes; the merged stream ends once every connection has ended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `chunk_size` is 0
+    /// - Any WebSocket connection fails or exceeds the configured connect timeout
+    /// - Any subscription message cannot be sent
+    pub async fn subscribe_chunked(
+        &self,
+        token_ids: Vec<String>,
+        chunk_size: usize,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<WsEvent>> + Send>>> {
+        if chunk_size == 0 {
+            return Err(Error::InvalidParameter(
+                "chunk_size must be greater than 0".to_string(),
+            ));
+        }
+
+        let streams = futures_util::future::try_join_all(
+            token_ids
+                .chunks(chunk_size)
+                .map(|chunk| self.subscribe(chunk.to_vec())),
+        )
+        .await?;
+
+        Ok(Box::pin(futures_util::stream::select_all(streams)))
+    }
+
+    /// Subscribe to incremental order book updates only
+    ///
+    /// Works like [`subscribe`](Self::subscribe), but yields only the
+    /// [`WsEvent::PriceChange`] deltas; book snapshots, trade prints and tick
+    /// size changes are dropped. Errors are passed through unchanged, so the
+    /// stream still reports [`Error::ConnectionClosed`] when the server closes
+    /// the connection.
+    ///
+    /// Without the initial snapshot the deltas can't be applied to a full
+    /// book; use [`OrderBook`](super::OrderBook) with
+    /// [`subscribe`](Self::subscribe) if you need one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
+    /// - The subscription message cannot be sent
+    pub async fn subscribe_price_changes(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<PriceChangeStream> {
+        let stream = self.subscribe(token_ids).await?;
+
+        Ok(Box::pin(stream.filter_map(|item| async move {
+            match item {
+                Ok(WsEvent::PriceChange(event)) => Some(Ok(event)),
+                Ok(_) => None,
+                Err(e) => Some(Err(e)),
+            }
+        })))
+    }
+
+    /// Subscribe to market updates with book and trade events on separate streams
+    ///
+    /// A single WebSocket connection is read by a background task that forwards
+    /// [`WsEvent::LastTradePrice`] events to the [`TradeStream`] and every other
+    /// event (and any parse error) to the [`BookStream`]. Both streams end when
+    /// the connection closes. Once both streams are dropped, the background task
+    /// stops and closes the connection, even if no more messages arrive.
+    ///
+    /// The channels are unbounded so a slow consumer of one stream never stalls the other.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_ids` - List of token/asset IDs to subscribe to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
+    /// - The subscription message cannot be sent
+    pub async fn subscribe_split(
+        &self,
+        token_ids: Vec<String>,
+    ) -> Result<(BookStream, TradeStream)> {
+        let mut stream = self.subscribe(token_ids).await?;
+
+        let (book_tx, book_rx) = mpsc::unbounded_channel();
+        let (trade_tx, trade_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            loop {
+                let item = tokio::select! {
+                    item = stream.next() => item,
+                    _ = async { tokio::join!(book_tx.closed(), trade_tx.closed()) } => break,
+                };
+                let Some(item) = item else { break };
+                match item {
+                    Ok(WsEvent::LastTradePrice(trade)) => {
+                        let _ = trade_tx.send(Ok(trade));
+                    }
+                    Err(Error::ConnectionClosed) => break,
+                    other => {
+                        let _ = book_tx.send(other);
+                    }
+                }
+            }
+        });
+
+        Ok((
+            Box::pin(receiver_stream(book_rx)),
+            Box::pin(receiver_stream(trade_rx)),
+        ))
+    }
 }
 
 impl Default for MarketWsClient {
@@ -275,6 +517,7 @@ impl Default for MarketWsClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::websocket::{MockWsServer, MockWsStep};
 
     #[test]
     fn test_client_creation() {
@@ -288,4 +531,246 @@ mod tests {
         let client = MarketWsClient::with_url(custom_url);
         assert_eq!(client.ws_url, custom_url);
     }
+
+    #[tokio::test]
+    async fn test_subscribe_split() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            // Subscription message
+            ws.next().await.unwrap().unwrap();
+            let book = r#"{"event_type": "book", "market": "0x1", "asset_id": "1",
+                "timestamp": "1", "hash": "h", "bids": [], "asks": []}"#;
+            let trade = r#"{"event_type": "last_trade_price", "market": "0x1", "asset_id": "1",
+                "price": "0.5", "size": "10", "fee_rate_bps": "0", "side": "BUY",
+                "timestamp": "2", "transaction_hash": "0xabc"}"#;
+            ws.send(Message::Text(book.to_string())).await.unwrap();
+            ws.send(Message::Text(trade.to_string())).await.unwrap();
+            ws.close(None).await.unwrap();
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let (mut books, mut trades) = client.subscribe_split(vec!["1".into()]).await.unwrap();
+
+        assert!(matches!(books.next().await, Some(Ok(WsEvent::Book(_)))));
+        let trade = trades.next().await.unwrap().unwrap();
+        assert_eq!(trade.transaction_hash, "0xabc");
+
+        // Both streams end when the connection closes
+        assert!(books.next().await.is_none());
+        assert!(trades.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_split_closes_when_streams_dropped() {
+        // An idle market: nothing arrives after the subscription
+        let server = serve_once(vec![]).await;
+        let client = MarketWsClient::with_url(server.url());
+        let (books, trades) = client.subscribe_split(vec!["1".into()]).await.unwrap();
+        assert_eq!(server.connections(), 1);
+
+        drop(books);
+        drop(trades);
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while server.closed_connections() == 0 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("connection still open after both streams were dropped");
+    }
+
+    /// Serve connections that send `messages` after the subscription
+    /// message, then stay open until the client goes away
+    async fn serve_once(messages: Vec<&'static str>) -> MockWsServer {
+        MockWsServer::new()
+            .connection(messages.into_iter().map(MockWsStep::text))
+            .start()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_confirmed_waits_for_book() {
+        let price_change = r#"{"event_type": "price_change", "market": "0x1", "price_changes": [],
+            "timestamp": "1"}"#;
+        let book = r#"{"event_type": "book", "market": "0x1", "asset_id": "1",
+            "timestamp": "1", "hash": "h", "bids": [], "asks": []}"#;
+        let server = serve_once(vec![price_change, book]).await;
+
+        let client = MarketWsClient::with_url(server.url());
+        let mut stream = client
+            .subscribe_confirmed(vec!["1".into()], Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(WsEvent::PriceChange(_)))
+        ));
+        assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_confirmed_times_out() {
+        let server = serve_once(vec![]).await;
+
+        let client = MarketWsClient::with_url(server.url());
+        let result = client
+            .subscribe_confirmed(vec!["1".into()], Duration::from_millis(50))
+            .await;
+        assert!(matches!(result, Err(Error::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_price_changes_filters_events() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            ws.next().await.unwrap().unwrap();
+            let book = r#"{"event_type": "book", "market": "0x1", "asset_id": "1",
+                "timestamp": "1", "hash": "h", "bids": [], "asks": []}"#;
+            let price_change = r#"{"event_type": "price_change", "market": "0x1",
+                "price_changes": [{"asset_id": "1", "side": "BUY", "price": "0.5", "size": "10"}]}"#;
+            let trade = r#"{"event_type": "last_trade_price", "market": "0x1", "asset_id": "1",
+                "price": "0.5", "size": "10", "fee_rate_bps": "0", "side": "BUY",
+                "timestamp": "2", "transaction_hash": "0xabc"}"#;
+            for message in [book, price_change, trade] {
+                ws.send(Message::Text(message.to_string())).await.unwrap();
+            }
+            ws.close(None).await.unwrap();
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let mut stream = client
+            .subscribe_price_changes(vec!["1".into()])
+            .await
+            .unwrap();
+
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(event.price_changes[0].asset_id, "1");
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ConnectionClosed))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_raw_tap_sees_unparsed_frames() {
+        let book = r#"{"event_type": "book", "market": "0x1", "asset_id": "1",
+            "timestamp": "1", "hash": "h", "bids": [], "asks": []}"#;
+        let server = serve_once(vec![r#"{"event_type": "price_change"}"#, book]).await;
+
+        let frames = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let tapped = frames.clone();
+        let client = MarketWsClient::with_url(server.url())
+            .with_raw_tap(move |text| tapped.lock().unwrap().push(text.to_string()));
+        let mut stream = client.subscribe(vec!["1".into()]).await.unwrap();
+
+        assert!(matches!(stream.next().await, Some(Err(_))));
+        assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(_)))));
+        let frames = frames.lock().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], r#"{"event_type": "price_change"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_chunked_merges_connections() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    // Reply with one book per subscribed token
+                    let msg = ws.next().await.unwrap().unwrap();
+                    let subscription: MarketSubscription =
+                        serde_json::from_str(msg.to_text().unwrap()).unwrap();
+                    for asset_id in subscription.assets_ids {
+                        let book = format!(
+                            r#"{{"event_type": "book", "market": "0x1", "asset_id": "{}",
+                                "timestamp": "1", "hash": "h", "bids": [], "asks": []}}"#,
+                            asset_id
+                        );
+                        ws.send(Message::Text(book)).await.unwrap();
+                    }
+                    ws.close(None).await.unwrap();
+                });
+            }
+        });
+
+        let client = MarketWsClient::with_url(format!("ws://{}", addr));
+        let tokens: Vec<String> = (1..=5).map(|i| i.to_string()).collect();
+        let stream = client.subscribe_chunked(tokens, 2).await.unwrap();
+
+        let events: Vec<Result<WsEvent>> = stream.collect().await;
+        let mut assets: Vec<String> = events
+            .iter()
+            .filter_map(|e| match e {
+                Ok(WsEvent::Book(book)) => Some(book.asset_id.clone()),
+                _ => None,
+            })
+            .collect();
+        assets.sort();
+        assert_eq!(assets, ["1", "2", "3", "4", "5"]);
+        // One close per connection: chunks of [1, 2], [3, 4], [5]
+        let closes = events
+            .iter()
+            .filter(|e| matches!(e, Err(Error::ConnectionClosed)))
+            .count();
+        assert_eq!(closes, 3);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_chunked_rejects_zero_chunk_size() {
+        let client = MarketWsClient::new();
+        let result = client.subscribe_chunked(vec!["1".into()], 0).await;
+        assert!(matches!(result, Err(Error::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_parse_array_yields_every_event() {
+        let text = r#"[
+            {"event_type": "price_change", "market": "0x1", "price_changes": [
+                {"asset_id": "1", "side": "BUY", "price": "0.5", "size": "10"}]},
+            {"event_type": "price_change", "market": "0x1", "price_changes": [
+                {"asset_id": "1", "side": "BUY", "price": "0.49", "size": "0"}]},
+            {"event_type": "tick_size_change", "market": "0x1", "asset_id": "1",
+             "old_tick_size": "0.01", "new_tick_size": "0.001", "timestamp": "1"}
+        ]"#;
+        let events = parse_ws_message(Ok(Message::Text(text.to_string())));
+
+        assert_eq!(events.len(), 3);
+        let prices: Vec<String> = events[..2]
+            .iter()
+            .map(|e| match e {
+                Ok(WsEvent::PriceChange(change)) => change.price_changes[0].price.to_string(),
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(prices, ["0.5", "0.49"]);
+        assert!(matches!(events[2], Ok(WsEvent::TickSizeChange(_))));
+
+        assert!(parse_ws_message(Ok(Message::Text("[]".to_string()))).is_empty());
+    }
+
+    #[test]
+    fn test_client_with_connect_timeout() {
+        let client = MarketWsClient::new();
+        assert_eq!(client.connect_timeout, None);
+
+        let client = client.with_connect_timeout(Duration::from_secs(5));
+        assert_eq!(client.connect_timeout, Some(Duration::from_secs(5)));
+    }
 }
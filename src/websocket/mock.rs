@@ -0,0 +1,277 @@
+//! Local WebSocket server for tests (`test-util` feature)
+
+use futures_util::{SinkExt, StreamExt};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::error::Result;
+
+/// One step of a [`MockWsServer`] connection script
+#[derive(Debug, Clone)]
+pub enum MockWsStep {
+    /// Send a text frame
+    Text(String),
+    /// Wait before the next step
+    Delay(Duration),
+    /// Send a Close frame with `code` and `reason`, then end the script
+    Close { code: u16, reason: String },
+    /// Drop the TCP connection without a Close frame
+    Disconnect,
+}
+
+impl MockWsStep {
+    /// Send `text`, e.g. a JSON event
+    pub fn text(text: impl Into<String>) -> Self {
+        MockWsStep::Text(text.into())
+    }
+
+    /// Close the connection with `code` and `reason`
+    pub fn close(code: u16, reason: impl Into<String>) -> Self {
+        MockWsStep::Close {
+            code,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Local WebSocket server replaying scripted messages
+///
+/// Each accepted connection plays one script, in the order they were added
+/// with [`connection`](Self::connection); connections beyond the last script
+/// replay the last one. A script starts once the client has sent its first
+/// message (the subscription or authentication message), and when it ends
+/// without closing, the connection stays open until the client leaves.
+/// Text messages sent by clients are recorded for
+/// [`received`](Self::received).
+///
+/// Point a client at [`url`](Self::url) with `with_url`. The server stops
+/// accepting connections when dropped.
+///
+/// ```
+/// # #[cfg(feature = "test-util")]
+/// # async fn example() -> polymarket_rs::Result<()> {
+/// use polymarket_rs::websocket::{MarketWsClient, MockWsServer, MockWsStep, WsEvent};
+/// use polymarket_rs::StreamExt;
+///
+/// let book = r#"{"event_type": "book", "market": "0x1", "asset_id": "1",
+///     "timestamp": "1", "hash": "h", "bids": [], "asks": []}"#;
+/// let server = MockWsServer::new()
+///     .connection([MockWsStep::text(book), MockWsStep::close(1001, "restart")])
+///     .start()
+///     .await?;
+///
+/// let mut stream = MarketWsClient::with_url(server.url()).subscribe(vec!["1".into()]).await?;
+/// assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(_)))));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockWsServer {
+    scripts: Vec<Vec<MockWsStep>>,
+    url: String,
+    connections: Arc<AtomicUsize>,
+    closed: Arc<AtomicUsize>,
+    received: Arc<Mutex<Vec<String>>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl MockWsServer {
+    /// Create a server with no scripts; connections stay open but silent
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the script for the next connection
+    pub fn connection(mut self, steps: impl IntoIterator<Item = MockWsStep>) -> Self {
+        self.scripts.push(steps.into_iter().collect());
+        self
+    }
+
+    /// Listen on a free local port and start accepting connections
+    pub async fn start(mut self) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        self.url = format!("ws://{}", listener.local_addr()?);
+
+        let scripts = std::mem::take(&mut self.scripts);
+        let connections = Arc::clone(&self.connections);
+        let closed = Arc::clone(&self.closed);
+        let received = Arc::clone(&self.received);
+        self.task = Some(tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let index = connections.fetch_add(1, Ordering::SeqCst);
+                let script = scripts
+                    .get(index)
+                    .or(scripts.last())
+                    .cloned()
+                    .unwrap_or_default();
+                let received = Arc::clone(&received);
+                let closed = Arc::clone(&closed);
+                tokio::spawn(async move {
+                    serve(socket, script, received).await;
+                    closed.fetch_add(1, Ordering::SeqCst);
+                });
+            }
+        }));
+        Ok(self)
+    }
+
+    /// `ws://` URL of the server
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Number of connections accepted so far
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// Number of connections that have ended, closed by either side
+    pub fn closed_connections(&self) -> usize {
+        self.closed.load(Ordering::SeqCst)
+    }
+
+    /// Text messages received from clients, across all connections
+    pub fn received(&self) -> Vec<String> {
+        self.received.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockWsServer {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+    }
+}
+
+/// Play `script` on one connection
+async fn serve(
+    socket: tokio::net::TcpStream,
+    script: Vec<MockWsStep>,
+    received: Arc<Mutex<Vec<String>>>,
+) {
+    let Ok(mut ws) = tokio_tungstenite::accept_async(socket).await else {
+        return;
+    };
+    let record = |message: Message| {
+        if let Message::Text(text) = message {
+            received.lock().unwrap().push(text);
+        }
+    };
+
+    match ws.next().await {
+        Some(Ok(message)) => record(message),
+        _ => return,
+    }
+    for step in script {
+        match step {
+            MockWsStep::Text(text) => {
+                if ws.send(Message::Text(text)).await.is_err() {
+                    return;
+                }
+            }
+            MockWsStep::Delay(delay) => tokio::time::sleep(delay).await,
+            MockWsStep::Close { code, reason } => {
+                let frame = CloseFrame {
+                    code: code.into(),
+                    reason: reason.into(),
+                };
+                let _ = ws.close(Some(frame)).await;
+                break;
+            }
+            MockWsStep::Disconnect => return,
+        }
+    }
+    // Keep the connection open, or finish the close handshake
+    while let Some(Ok(message)) = ws.next().await {
+        record(message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::types::WsEvent;
+    use crate::websocket::{MarketWsClient, ReconnectConfig, ReconnectingStream};
+
+    fn book(asset_id: &str) -> MockWsStep {
+        MockWsStep::text(format!(
+            r#"{{"event_type": "book", "market": "0x1", "asset_id": "{}",
+                "timestamp": "1", "hash": "h", "bids": [], "asks": []}}"#,
+            asset_id
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_yields_scripted_events() {
+        let server = MockWsServer::new()
+            .connection([
+                book("1"),
+                MockWsStep::text(
+                    r#"[{"event_type": "tick_size_change", "market": "0x1",
+                    "asset_id": "1", "old_tick_size": "0.01", "new_tick_size": "0.001",
+                    "timestamp": "2"}]"#,
+                ),
+                MockWsStep::close(1000, "bye"),
+            ])
+            .start()
+            .await
+            .unwrap();
+
+        let client = MarketWsClient::with_url(server.url());
+        let mut stream = client.subscribe(vec!["1".into()]).await.unwrap();
+
+        assert!(matches!(stream.next().await, Some(Ok(WsEvent::Book(_)))));
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(WsEvent::TickSizeChange(_)))
+        ));
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ConnectionClosed))
+        ));
+
+        let subscription: serde_json::Value = serde_json::from_str(&server.received()[0]).unwrap();
+        assert_eq!(subscription["assets_ids"][0], "1");
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_stream_reconnects_after_close() {
+        let server = MockWsServer::new()
+            .connection([book("1"), MockWsStep::close(1001, "going away")])
+            .connection([book("2")])
+            .start()
+            .await
+            .unwrap();
+
+        let client = MarketWsClient::with_url(server.url());
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let stream = ReconnectingStream::new(config, move || {
+            let client = client.clone();
+            async move { client.subscribe(vec!["1".into()]).await }
+        });
+
+        let assets: Vec<String> = stream
+            .filter_map(|event| async move {
+                match event {
+                    Ok(WsEvent::Book(book)) => Some(book.asset_id),
+                    _ => None,
+                }
+            })
+            .take(2)
+            .collect()
+            .await;
+        assert_eq!(assets, ["1", "2"]);
+        assert_eq!(server.connections(), 2);
+        assert_eq!(server.received().len(), 2);
+    }
+}
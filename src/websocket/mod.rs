@@ -9,12 +9,51 @@
 //! The Polymarket WebSocket server may disconnect idle connections after 1-2 minutes.
 //! For production use, it's recommended to use [`ReconnectingStream`] to automatically
 //! handle disconnections and reconnect with exponential backoff.
+//!
+//! # Compression
+//!
+//! Connections are not compressed. `permessage-deflate` is not supported by
+//! tungstenite 0.24, which this crate uses: the extension is never offered
+//! in the handshake, and frames with the compression bit set are rejected, so
+//! servers always fall back to uncompressed messages. Compression can be
+//! offered once the crate moves to a tungstenite release with deflate
+//! support. To reduce bandwidth meanwhile, subscribe only to the tokens in
+//! use, or split large token lists across connections with
+//! [`MarketWsClient::subscribe_chunked`].
+//!
+//! # Local Order Books
+//!
+//! [`OrderBook`] keeps an asset's book up to date from `book` and
+//! `price_change` events, and [`throttled_book`] turns an event stream into
+//! book snapshots at a fixed cadence, e.g. for charts.
+//!
+//! # Testing
+//!
+//! With the `test-util` feature, [`MockWsServer`] serves scripted messages
+//! on a local port, so code built on these clients can be tested without
+//! the real servers, including reconnects after a forced close.
+//!
+//! # Close Reasons
+//!
+//! When the server closes a user stream with a Close frame, the stream yields
+//! [`Error::ServerClosed`](crate::Error::ServerClosed) with a typed
+//! [`CloseReason`]. Reasons that can't be fixed by reconnecting, such as
+//! rejected credentials, end a [`ReconnectingStream`] instead of retrying.
 
+mod book;
+mod close;
+mod connection;
 mod market;
+#[cfg(any(test, feature = "test-util"))]
+mod mock;
 mod stream;
 mod user;
 
-pub use market::{MarketWsClient, SubscriptionHandle};
+pub use book::{throttled_book, LevelUpdate, OrderBook};
+pub use close::CloseReason;
+pub use market::{BookStream, MarketWsClient, PriceChangeStream, SubscriptionHandle, TradeStream};
+#[cfg(any(test, feature = "test-util"))]
+pub use mock::{MockWsServer, MockWsStep};
 pub use stream::{ReconnectConfig, ReconnectingStream};
 pub use user::UserWsClient;
 
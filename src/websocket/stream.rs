@@ -18,6 +18,20 @@ pub struct ReconnectConfig {
     pub multiplier: f64,
     /// Maximum number of reconnection attempts (None = infinite)
     pub max_attempts: Option<u32>,
+    /// Keep a second, subscribed connection open to fail over to
+    ///
+    /// While connected, [`ReconnectingStream`] opens a standby connection
+    /// with the same connect function, so the same subscription. When the
+    /// primary connection drops, the standby takes over on the same poll,
+    /// without a reconnect delay or handshake. Events received on the standby
+    /// while the primary is alive are duplicates and are discarded.
+    ///
+    /// Events sent while the primary was failing are not replayed, and the
+    /// initial snapshot the server sends on subscribing was consumed long
+    /// before the failover, so local order books should be resynced after
+    /// one. Doubles the number of open connections; a standby that fails
+    /// to connect is retried after `max_delay`.
+    pub warm_standby: bool,
 }
 
 impl Default for ReconnectConfig {
@@ -27,6 +41,7 @@ impl Default for ReconnectConfig {
             max_delay: Duration::from_secs(60),
             multiplier: 2.0,
             max_attempts: None,
+            warm_standby: false,
         }
     }
 }
@@ -82,12 +97,26 @@ enum StreamState<S, Fut> {
     Terminated,
 }
 
+/// Spare connection kept when [`ReconnectConfig::warm_standby`] is set
+enum Standby<S, Fut> {
+    /// Waiting to retry after the standby failed
+    Backoff(Pin<Box<tokio::time::Sleep>>),
+    /// Connection in progress
+    Connecting(Pin<Box<Fut>>),
+    /// Connected and subscribed, ready to take over
+    Ready(S),
+}
+
 /// A stream wrapper that automatically reconnects on disconnection
 ///
 /// This wrapper provides resilient streaming by:
 /// - Automatically reconnecting when the connection is lost
 /// - Using exponential backoff between reconnection attempts
 /// - Optionally limiting the number of reconnection attempts
+/// - Optionally failing over to a warm standby connection
+///   (see [`ReconnectConfig::warm_standby`])
+/// - Stopping when the server closes the connection for a reason that
+///   reconnecting can't fix (see [`CloseReason::is_retryable`](super::CloseReason::is_retryable))
 ///
 /// # Example
 ///
@@ -133,6 +162,8 @@ where
     backoff: ExponentialBackoff,
     /// Sleep future for reconnection delay
     sleep_future: Option<Pin<Box<tokio::time::Sleep>>>,
+    /// Standby connection, only kept while connected
+    standby: Option<Standby<S, Fut>>,
 }
 
 impl<T, S, F, Fut> ReconnectingStream<T, S, F, Fut>
@@ -163,26 +194,118 @@ where
             config,
             backoff,
             sleep_future: None,
+            standby: None,
         }
     }
 
+    /// Stop the stream and close the current connection
+    ///
+    /// Drops the active connection (market and user streams send a Close
+    /// frame when dropped) or any connection attempt in flight, and cancels a
+    /// pending reconnect delay. The stream then yields `None` and never
+    /// reconnects. Calling this more than once is a no-op.
+    ///
+    /// Dropping the stream has the same effect: it owns its connection and
+    /// connect future and spawns no background tasks, so nothing outlives it.
+    pub fn shutdown(&mut self) {
+        self.state = StreamState::Terminated;
+        self.sleep_future = None;
+        self.standby = None;
+    }
+
+    /// Whether the stream has stopped, via [`shutdown`](Self::shutdown) or
+    /// after running out of reconnection attempts
+    pub fn is_terminated(&self) -> bool {
+        matches!(self.state, StreamState::Terminated)
+    }
+
     /// Handle a disconnection and prepare for reconnection
-    fn handle_disconnection(&mut self, attempts: u32) -> Poll<Option<Result<T>>> {
+    ///
+    /// Returns the error to end the stream with once the reconnection
+    /// attempts are used up. Otherwise the stream waits in the reconnecting
+    /// state, which the caller must poll to register for the delay's wakeup.
+    fn handle_disconnection(&mut self, attempts: u32) -> Option<Error> {
         // Check if we've exceeded max attempts
         if let Some(max) = self.config.max_attempts {
             if attempts >= max {
                 self.state = StreamState::Terminated;
-                return Poll::Ready(Some(Err(Error::ReconnectFailed {
+                return Some(Error::ReconnectFailed {
                     attempts,
                     last_error: "Maximum reconnection attempts reached".to_string(),
-                })));
+                });
             }
         }
 
+        // A standby still connecting is given up; a new one is opened once connected again
+        self.standby = None;
         let delay = self.backoff.next_delay();
+        #[cfg(feature = "metrics")]
+        crate::metrics::record(|m| m.reconnect(attempts));
         self.state = StreamState::Reconnecting { attempts, delay };
         self.sleep_future = Some(Box::pin(sleep(delay)));
-        Poll::Pending
+        None
+    }
+
+    /// Advance the standby connection: open it, retry it, or discard the
+    /// duplicate events it receives
+    fn poll_standby(&mut self, cx: &mut Context<'_>) {
+        loop {
+            match self.standby.take() {
+                None => {
+                    self.standby = Some(Standby::Connecting(Box::pin((self.connect_fn)())));
+                }
+                Some(Standby::Backoff(mut delay)) => {
+                    if delay.as_mut().poll(cx).is_pending() {
+                        self.standby = Some(Standby::Backoff(delay));
+                        return;
+                    }
+                }
+                Some(Standby::Connecting(mut future)) => match future.as_mut().poll(cx) {
+                    Poll::Ready(Ok(stream)) => self.standby = Some(Standby::Ready(stream)),
+                    Poll::Ready(Err(e)) => {
+                        log::warn!("Standby connection failed: {}", e);
+                        self.standby =
+                            Some(Standby::Backoff(Box::pin(sleep(self.config.max_delay))));
+                    }
+                    Poll::Pending => {
+                        self.standby = Some(Standby::Connecting(future));
+                        return;
+                    }
+                },
+                Some(Standby::Ready(mut stream)) => match Pin::new(&mut stream).poll_next(cx) {
+                    // The primary delivers the same events
+                    Poll::Ready(Some(Ok(_))) => self.standby = Some(Standby::Ready(stream)),
+                    Poll::Ready(Some(Err(_))) | Poll::Ready(None) => {
+                        log::warn!("Standby connection lost");
+                        self.standby =
+                            Some(Standby::Backoff(Box::pin(sleep(self.config.max_delay))));
+                    }
+                    Poll::Pending => {
+                        self.standby = Some(Standby::Ready(stream));
+                        return;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Replace the lost primary connection with the standby, if it's ready
+    ///
+    /// The swap happens within a single poll, so no event of the old
+    /// connection can follow one of the new.
+    fn promote_standby(&mut self) -> bool {
+        match self.standby.take() {
+            Some(Standby::Ready(stream)) => {
+                log::info!("Primary connection lost, switching to standby");
+                self.state = StreamState::Connected(stream);
+                self.backoff.reset();
+                true
+            }
+            standby => {
+                self.standby = standby;
+                false
+            }
+        }
     }
 }
 
@@ -202,22 +325,51 @@ where
                         Poll::Ready(Some(Ok(item))) => {
                             // Successfully received an item, reset backoff
                             self.backoff.reset();
+                            if self.config.warm_standby {
+                                self.poll_standby(cx);
+                            }
                             return Poll::Ready(Some(Ok(item)));
                         }
                         Poll::Ready(Some(Err(Error::ConnectionClosed))) => {
-                            // Connection closed, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            // Connection closed, fail over or prepare to reconnect
+                            if self.promote_standby() {
+                                continue;
+                            }
+                            match self.handle_disconnection(1) {
+                                Some(e) => return Poll::Ready(Some(Err(e))),
+                                None => continue,
+                            }
+                        }
+                        Poll::Ready(Some(Err(e @ Error::ServerClosed { reason, .. })))
+                            if !reason.is_retryable() =>
+                        {
+                            // Reconnecting won't help, e.g. the credentials were rejected
+                            self.shutdown();
+                            return Poll::Ready(Some(Err(e)));
                         }
                         Poll::Ready(Some(Err(e))) => {
-                            // Other error, pass through and prepare to reconnect
-                            let _ = self.handle_disconnection(1);
+                            // Other error, pass through and fail over or prepare to reconnect
+                            if !self.promote_standby() {
+                                let _ = self.handle_disconnection(1);
+                            }
                             return Poll::Ready(Some(Err(e)));
                         }
                         Poll::Ready(None) => {
-                            // Stream ended, prepare to reconnect
-                            return self.handle_disconnection(1);
+                            // Stream ended, fail over or prepare to reconnect
+                            if self.promote_standby() {
+                                continue;
+                            }
+                            match self.handle_disconnection(1) {
+                                Some(e) => return Poll::Ready(Some(Err(e))),
+                                None => continue,
+                            }
                         }
                         Poll::Pending => {
+                            // Only drained while the primary is alive, so events the
+                            // standby gets after the primary dropped are kept
+                            if self.config.warm_standby {
+                                self.poll_standby(cx);
+                            }
                             return Poll::Pending;
                         }
                     }
@@ -269,7 +421,10 @@ where
                             // Connection failed, prepare to reconnect
                             // Increment attempts (or start at 1 if this is the first attempt)
                             let next_attempts = if current_attempts == 0 { 1 } else { current_attempts + 1 };
-                            return self.handle_disconnection(next_attempts);
+                            match self.handle_disconnection(next_attempts) {
+                                Some(e) => return Poll::Ready(Some(Err(e))),
+                                None => continue,
+                            }
                         }
                         Poll::Pending => {
                             // Store the future for next poll
@@ -322,6 +477,142 @@ mod tests {
         assert_eq!(backoff.next_delay(), Duration::from_secs(5)); // still capped
     }
 
+    #[tokio::test]
+    async fn test_shutdown_closes_connection() {
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let connects = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connects);
+        let mut stream = ReconnectingStream::new(ReconnectConfig::default(), move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok(futures_util::stream::iter(vec![Ok(1), Ok(2)])) }
+        });
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        stream.shutdown();
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_close_stops_stream() {
+        use crate::websocket::CloseReason;
+        use futures_util::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let connects = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connects);
+        let mut stream = ReconnectingStream::new(ReconnectConfig::default(), move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async {
+                Ok(futures_util::stream::iter(vec![Err::<(), _>(
+                    Error::ServerClosed {
+                        reason: CloseReason::AuthFailed,
+                        message: "invalid api key".to_string(),
+                    },
+                )]))
+            }
+        });
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(Error::ServerClosed {
+                reason: CloseReason::AuthFailed,
+                ..
+            }))
+        ));
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drops_pending_connect() {
+        use futures_util::stream::Empty;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        /// Sets the flag when the pending connect future is dropped
+        struct DropFlag(Arc<AtomicBool>);
+        impl Drop for DropFlag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&dropped);
+        let mut stream = ReconnectingStream::new(ReconnectConfig::default(), move || {
+            let guard = DropFlag(Arc::clone(&flag));
+            async move {
+                let _guard = guard;
+                std::future::pending::<Result<Empty<Result<()>>>>().await
+            }
+        });
+
+        // Start the connection attempt, which never completes
+        let waker = futures_util::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(Pin::new(&mut stream).poll_next(&mut cx).is_pending());
+        assert!(!dropped.load(Ordering::SeqCst));
+
+        stream.shutdown();
+        assert!(dropped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_warm_standby_fails_over() {
+        use futures_util::{FutureExt, StreamExt};
+        use std::collections::VecDeque;
+        use std::sync::{Arc, Mutex};
+        use tokio::sync::mpsc;
+
+        // Each connection yields what is sent on its channel
+        let mut senders = Vec::new();
+        let mut receivers = VecDeque::new();
+        for _ in 0..3 {
+            let (tx, rx) = mpsc::unbounded_channel::<Result<u32>>();
+            senders.push(tx);
+            receivers.push_back(rx);
+        }
+        let receivers = Arc::new(Mutex::new(receivers));
+        let connections = Arc::clone(&receivers);
+        let config = ReconnectConfig {
+            warm_standby: true,
+            ..Default::default()
+        };
+        let mut stream = ReconnectingStream::new(config, move || {
+            let rx = connections.lock().unwrap().pop_front().unwrap();
+            async move {
+                Ok(Box::pin(futures_util::stream::unfold(
+                    rx,
+                    |mut rx| async move { rx.recv().await.map(|item| (item, rx)) },
+                )))
+            }
+        });
+
+        senders[0].send(Ok(1)).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        // The standby is connected, and its events are dropped
+        assert_eq!(receivers.lock().unwrap().len(), 1);
+        senders[1].send(Ok(10)).unwrap();
+        senders[0].send(Ok(2)).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+
+        // The primary drops and the standby takes over without a reconnect delay
+        let primary = senders.remove(0);
+        drop(primary);
+        senders[0].send(Ok(3)).unwrap();
+        let next = stream.next().now_or_never().unwrap();
+        assert_eq!(next.unwrap().unwrap(), 3);
+        // and a new standby is opened
+        assert!(receivers.lock().unwrap().is_empty());
+    }
+
     #[test]
     fn test_backoff_reset() {
         let mut backoff = ExponentialBackoff::new(
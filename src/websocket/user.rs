@@ -1,7 +1,10 @@
 use futures_util::{SinkExt, Stream, StreamExt};
 use std::pin::Pin;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::Message;
 
+use super::close::close_error;
+use super::connection::{connect, parse_text_events, ClosingStream};
 use crate::error::{Error, Result};
 use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 
@@ -39,6 +42,7 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 ///         max_delay: Duration::from_secs(30),
 ///         multiplier: 2.0,
 ///         max_attempts: None,
+///         warm_standby: false,
 ///     };
 ///
 ///     let creds_clone = creds.clone();
@@ -60,6 +64,7 @@ use crate::types::{ApiCreds, UserAuthentication, UserWsEvent};
 #[derive(Debug, Clone)]
 pub struct UserWsClient {
     ws_url: String,
+    connect_timeout: Option<Duration>,
 }
 
 impl UserWsClient {
@@ -70,6 +75,7 @@ impl UserWsClient {
     pub fn new() -> Self {
         Self {
             ws_url: Self::DEFAULT_WS_URL.to_string(),
+            connect_timeout: None,
         }
     }
 
@@ -77,9 +83,19 @@ impl UserWsClient {
     pub fn with_url(ws_url: impl Into<String>) -> Self {
         Self {
             ws_url: ws_url.into(),
+            connect_timeout: None,
         }
     }
 
+    /// Set a timeout for the WebSocket handshake
+    ///
+    /// If the connection is not established within `timeout`, subscribing
+    /// fails with [`Error::Timeout`]. By default there is no timeout.
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
     /// Subscribe to user events with API credentials
     ///
     /// Returns a stream of [`UserWsEvent`] items. The stream will yield events as they
@@ -99,6 +115,7 @@ impl UserWsClient {
     ///
     /// Returns an error if:
     /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
     /// - The authentication message cannot be sent
     /// - Authentication fails (server will close the connection)
     ///
@@ -137,6 +154,36 @@ impl UserWsClient {
         .await
     }
 
+    /// Subscribe to user events for several accounts on one merged stream
+    ///
+    /// Opens one authenticated connection per credential set, concurrently,
+    /// and merges their events in arrival order. Each item is tagged with the
+    /// API key of the account it came from, errors included, so a failure on
+    /// one connection can be attributed to its account. If any connection
+    /// fails to open, the others are dropped and the error is returned.
+    ///
+    /// The merged stream ends once every connection has ended. Wrap each
+    /// account in its own [`ReconnectingStream`](crate::websocket::ReconnectingStream)
+    /// instead if connections need to be restarted independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any WebSocket connection fails, exceeds the
+    /// configured connect timeout, or can't send its authentication message.
+    pub async fn subscribe_multi(
+        &self,
+        creds: Vec<ApiCreds>,
+    ) -> Result<Pin<Box<dyn Stream<Item = (String, Result<UserWsEvent>)> + Send>>> {
+        let streams = futures_util::future::try_join_all(creds.into_iter().map(|creds| async {
+            let stream = self.subscribe_with_creds(&creds).await?;
+            let api_key = creds.api_key;
+            Ok::<_, Error>(stream.map(move |event| (api_key.clone(), event)))
+        }))
+        .await?;
+
+        Ok(Box::pin(futures_util::stream::select_all(streams)))
+    }
+
     /// Subscribe to user events with authentication
     ///
     /// Returns a stream of [`UserWsEvent`] items. The stream will yield events as they
@@ -158,6 +205,7 @@ impl UserWsClient {
     ///
     /// Returns an error if:
     /// - The WebSocket connection fails
+    /// - The handshake exceeds the configured connect timeout
     /// - The authentication message cannot be sent
     /// - Authentication fails (server will close the connection)
     pub async fn subscribe(
@@ -167,9 +215,7 @@ impl UserWsClient {
         api_passphrase: String,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<UserWsEvent>> + Send>>> {
         // Connect to the WebSocket endpoint
-        let (ws_stream, _) = connect_async(&self.ws_url).await?;
-
-        let (mut write, read) = ws_stream.split();
+        let mut ws_stream = connect(&self.ws_url, self.connect_timeout).await?;
 
         // Create authentication message
         let auth = UserAuthentication::new(api_key, api_secret, api_passphrase);
@@ -177,72 +223,59 @@ impl UserWsClient {
         let auth_msg = serde_json::to_string(&auth)?;
 
         // Send authentication message
-        write
+        ws_stream
             .send(Message::Text(auth_msg))
             .await
             .map_err(|e| Error::WebSocket(e.to_string()))?;
 
-        // Return stream that parses events
-        let stream = read.filter_map(|msg| async move {
-            match msg {
-                Ok(Message::Text(text)) => {
-                    // The server can send either a single object or an array
-                    // Try to parse as array first
-                    if let Ok(events) = serde_json::from_str::<Vec<serde_json::Value>>(&text) {
-                        // Got an array, take the first event
-                        if let Some(first) = events.first() {
-                            match serde_json::from_value::<UserWsEvent>(first.clone()) {
-                                Ok(event) => return Some(Ok(event)),
-                                Err(e) => return Some(Err(Error::Json(e))),
-                            }
-                        } else {
-                            // Empty array, ignore
-                            return None;
-                        }
-                    }
+        // Keep the write half alive so dropping the stream sends a Close frame
+        let read = ClosingStream::new(ws_stream);
 
-                    // Try parsing as single object
-                    match serde_json::from_str::<UserWsEvent>(&text) {
-                        Ok(event) => Some(Ok(event)),
-                        Err(e) => Some(Err(Error::Json(e))),
-                    }
-                }
-                Ok(Message::Close(close_frame)) => {
-                    // Connection closed - may indicate auth failure
-                    if let Some(frame) = close_frame {
-                        Some(Err(Error::WebSocket(format!(
-                            "Connection closed: code={}, reason={}",
-                            frame.code, frame.reason
-                        ))))
-                    } else {
-                        Some(Err(Error::ConnectionClosed))
-                    }
-                }
-                Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
-                    // Ignore ping/pong frames (handled automatically)
-                    None
-                }
-                Ok(Message::Binary(_)) => {
-                    // Unexpected binary message
-                    Some(Err(Error::WebSocket(
-                        "Unexpected binary message".to_string(),
-                    )))
-                }
-                Ok(Message::Frame(_)) => {
-                    // Raw frame (shouldn't happen)
-                    None
-                }
-                Err(e) => {
-                    // WebSocket error
-                    Some(Err(Error::WebSocket(e.to_string())))
-                }
-            }
-        });
+        // Return stream that parses events, flattening batched arrays
+        let stream = read.flat_map(|msg| futures_util::stream::iter(parse_user_ws_message(msg)));
 
         Ok(Box::pin(stream))
     }
 }
 
+/// Parse a WebSocket message into UserWsEvents
+///
+/// A message carrying an array of events yields all of them, in order.
+fn parse_user_ws_message(
+    msg: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Vec<Result<UserWsEvent>> {
+    match msg {
+        // The server can send either a single object or an array
+        Ok(Message::Text(text)) => {
+            #[cfg(feature = "metrics")]
+            crate::metrics::record(|m| m.message_received("user"));
+            parse_text_events(&text)
+        }
+        Ok(Message::Close(close_frame)) => {
+            // Connection closed - the code tells auth failures from restarts
+            vec![Err(close_error(close_frame))]
+        }
+        Ok(Message::Ping(_)) | Ok(Message::Pong(_)) => {
+            // Ignore ping/pong frames (handled automatically)
+            Vec::new()
+        }
+        Ok(Message::Binary(_)) => {
+            // Unexpected binary message
+            vec![Err(Error::WebSocket(
+                "Unexpected binary message".to_string(),
+            ))]
+        }
+        Ok(Message::Frame(_)) => {
+            // Raw frame (shouldn't happen)
+            Vec::new()
+        }
+        Err(e) => {
+            // WebSocket error
+            vec![Err(Error::WebSocket(e.to_string()))]
+        }
+    }
+}
+
 impl Default for UserWsClient {
     fn default() -> Self {
         Self::new()
@@ -258,4 +291,85 @@ mod tests {
         let client = UserWsClient::new();
         assert_eq!(client.ws_url, UserWsClient::DEFAULT_WS_URL);
     }
+
+    #[test]
+    fn test_parse_array_yields_every_event() {
+        let order = |id: &str, size_matched: &str| {
+            format!(
+                r#"{{"asset_id": "1", "associate_trades": null, "event_type": "order",
+                    "id": "{}", "market": "0x1", "order_owner": "owner", "order_type": "GTC",
+                    "maker_address": "0x2",
+                    "original_size": "10", "outcome": "YES", "owner": "owner",
+                    "price": "0.57", "side": "SELL", "size_matched": "{}",
+                    "status": "LIVE", "type": "UPDATE"}}"#,
+                id, size_matched
+            )
+        };
+        let text = format!("[{}, {}]", order("0xa", "1"), order("0xb", "2"));
+
+        let events = parse_user_ws_message(Ok(Message::Text(text)));
+        let ids: Vec<String> = events
+            .into_iter()
+            .map(|e| match e {
+                Ok(UserWsEvent::Order(order)) => order.id,
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(ids, ["0xa", "0xb"]);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_multi_tags_events_by_account() {
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            loop {
+                let (socket, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                    // Echo the account's API key back as the order id
+                    let auth = ws.next().await.unwrap().unwrap().into_text().unwrap();
+                    let auth: serde_json::Value = serde_json::from_str(&auth).unwrap();
+                    let api_key = auth["auth"]["apiKey"].as_str().unwrap().to_string();
+                    let order = format!(
+                        r#"{{"asset_id": "1", "associate_trades": null, "event_type": "order",
+                            "id": "{}", "market": "0x1", "order_owner": "owner",
+                            "order_type": "GTC", "maker_address": "0x2",
+                            "original_size": "10", "outcome": "YES", "owner": "owner",
+                            "price": "0.57", "side": "SELL", "size_matched": "0",
+                            "status": "LIVE", "type": "PLACEMENT"}}"#,
+                        api_key
+                    );
+                    ws.send(Message::Text(order)).await.unwrap();
+                });
+            }
+        });
+
+        let creds = |key: &str| ApiCreds::new(key.into(), "secret".into(), "pass".into());
+        let stream = UserWsClient::with_url(url)
+            .subscribe_multi(vec![creds("key-a"), creds("key-b")])
+            .await
+            .unwrap();
+
+        let mut tagged: Vec<(String, String)> = stream
+            .filter_map(|(api_key, event)| async move {
+                match event {
+                    Ok(UserWsEvent::Order(order)) => Some((api_key, order.id)),
+                    _ => None,
+                }
+            })
+            .take(2)
+            .collect()
+            .await;
+        tagged.sort();
+        assert_eq!(
+            tagged,
+            [
+                ("key-a".to_string(), "key-a".to_string()),
+                ("key-b".to_string(), "key-b".to_string())
+            ]
+        );
+    }
 }